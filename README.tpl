@@ -14,10 +14,18 @@
 
 Current version: {{version}}
 
+## Minimum supported Rust version
+
+The minimum supported Rust version is 1.82 when building with stable features,
+`nightly_features` requires a recent nightly compiler
+
 ## no_std
 
 This crate should work without the full standard library
 
+Enable the `alloc` feature for collecting into and iterating over heap allocated
+collections like `Vec<T>` and `Box<[T; N]>`
+
 Some additional info here
 
 # License : {{license}}
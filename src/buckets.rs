@@ -0,0 +1,13 @@
+/// Accumulators of a fixed number of buckets, created by [`crate::IteratorFixed::histogram`]
+/// and [`crate::IteratorFixed::group_into`].
+///
+/// Elements assigned to a bucket index that is out of range are not accumulated but counted
+/// in [`Buckets::overflow`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Buckets<C, const K: usize> {
+    /// The accumulator of every bucket
+    pub buckets: [C; K],
+
+    /// The number of elements that were assigned to a bucket index that is out of range
+    pub overflow: usize,
+}
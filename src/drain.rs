@@ -0,0 +1,65 @@
+use core::{iter::FusedIterator, mem::MaybeUninit, ops::Range, ptr};
+
+/// Iterator moving the elements out of one part of an array in place, created by
+/// [`IteratorFixed::split_array_at_exact`](crate::IteratorFixed::split_array_at_exact)
+///
+/// The elements stay in the storage of the split array until they are yielded, the elements
+/// that have not been yielded are dropped together with the iterator.
+pub struct ArrayDrain<'a, T> {
+    slots: &'a mut [MaybeUninit<T>],
+    alive: Range<usize>,
+}
+
+impl<'a, T> ArrayDrain<'a, T> {
+    /// # Safety
+    /// All elements of `slots` have to be initialized, they are owned by the iterator from
+    /// now on and may not be read or dropped by anyone else
+    #[inline]
+    pub(crate) unsafe fn new(slots: &'a mut [MaybeUninit<T>]) -> Self {
+        let alive = 0..slots.len();
+        Self { slots, alive }
+    }
+}
+
+impl<T> Iterator for ArrayDrain<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let i = self.alive.next()?;
+        // Safety: Elements in `alive` are initialized and i is removed from it
+        Some(unsafe { self.slots[i].assume_init_read() })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.alive.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for ArrayDrain<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        let i = self.alive.next_back()?;
+        // Safety: Elements in `alive` are initialized and i is removed from it
+        Some(unsafe { self.slots[i].assume_init_read() })
+    }
+}
+
+impl<T> ExactSizeIterator for ArrayDrain<'_, T> {}
+
+impl<T> FusedIterator for ArrayDrain<'_, T> {}
+
+impl<T> Drop for ArrayDrain<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        let alive = &mut self.slots[self.alive.clone()];
+        // Safety: Elements in `alive` are initialized and never read again
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                alive.as_mut_ptr().cast::<T>(),
+                alive.len(),
+            ))
+        }
+    }
+}
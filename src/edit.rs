@@ -0,0 +1,43 @@
+/// Iterator yielding `item` after the first `index` elements of `inner`, see
+/// [`crate::IteratorFixed::insert`]
+pub(crate) struct Insert<I: Iterator> {
+    inner: I,
+    item: Option<I::Item>,
+    index: usize,
+}
+
+impl<I: Iterator> Insert<I> {
+    pub(crate) fn new(inner: I, index: usize, item: I::Item) -> Self {
+        Self {
+            inner,
+            item: Some(item),
+            index,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Insert<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == 0 {
+            if let Some(item) = self.item.take() {
+                return Some(item);
+            }
+        } else {
+            self.index -= 1;
+        }
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let extra = usize::from(self.item.is_some());
+        let (lower, upper) = self.inner.size_hint();
+        (
+            lower.saturating_add(extra),
+            upper.and_then(|upper| upper.checked_add(extra)),
+        )
+    }
+}
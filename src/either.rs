@@ -0,0 +1,115 @@
+use core::iter::FusedIterator;
+
+use crate::{IntoIteratorFixed, IteratorFixed};
+
+/// One of two iterators of the same length, created by
+/// [`crate::IteratorFixed::left_fixed`] and [`crate::IteratorFixed::right_fixed`]
+///
+/// This allows choosing between two differently typed pipelines at runtime without boxing
+/// or collecting into a temporary array.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    /// The left iterator
+    Left(L),
+
+    /// The right iterator
+    Right(R),
+}
+
+impl<T, L, R> Iterator for Either<L, R>
+where
+    L: Iterator<Item = T>,
+    R: Iterator<Item = T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        match self {
+            Self::Left(l) => l.next(),
+            Self::Right(r) => r.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Left(l) => l.size_hint(),
+            Self::Right(r) => r.size_hint(),
+        }
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        match self {
+            Self::Left(l) => l.fold(init, f),
+            Self::Right(r) => r.fold(init, f),
+        }
+    }
+}
+
+impl<T, L, R> DoubleEndedIterator for Either<L, R>
+where
+    L: DoubleEndedIterator<Item = T>,
+    R: DoubleEndedIterator<Item = T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        match self {
+            Self::Left(l) => l.next_back(),
+            Self::Right(r) => r.next_back(),
+        }
+    }
+}
+
+impl<T, L, R> ExactSizeIterator for Either<L, R>
+where
+    L: ExactSizeIterator<Item = T>,
+    R: ExactSizeIterator<Item = T>,
+{
+}
+
+impl<T, L, R> FusedIterator for Either<L, R>
+where
+    L: FusedIterator<Item = T>,
+    R: FusedIterator<Item = T>,
+{
+}
+
+unsafe impl<L, R, const N: usize> IntoIteratorFixed<N> for Either<L, R>
+where
+    L: IntoIteratorFixed<N>,
+    R: IntoIteratorFixed<N, Item = L::Item>,
+{
+    type Item = L::Item;
+    type IntoIter = Either<L::IntoIter, R::IntoIter>;
+
+    /// Creates a fixed size iterator from either of two values of the same length.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::{Either, IntoIteratorFixed};
+    ///
+    /// let identity = false;
+    /// let source = if identity {
+    ///     Either::Left([1, 2, 3])
+    /// } else {
+    ///     Either::Right(iter_fixed::from_fn(|i| 10 * i))
+    /// };
+    ///
+    /// let a: [_; 3] = source.into_iter_fixed().map(|x| x + 1).collect();
+    /// assert_eq!(a, [1, 11, 21]);
+    /// ```
+    #[inline]
+    fn into_iter_fixed(self) -> IteratorFixed<Self::IntoIter, N> {
+        let inner = match self {
+            Self::Left(l) => Either::Left(l.into_iter_fixed().inner),
+            Self::Right(r) => Either::Right(r.into_iter_fixed().inner),
+        };
+        // Safety: Both sides yield N elements
+        unsafe { IteratorFixed::from_iter(inner) }
+    }
+}
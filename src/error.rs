@@ -0,0 +1,25 @@
+use core::fmt;
+
+/// Error returned by [`crate::IteratorFixed::zip_checked`] when an iterator yielded fewer
+/// elements than expected
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LengthError {
+    /// The number of elements that were expected
+    pub expected: usize,
+
+    /// The number of elements the iterator actually yielded
+    pub actual: usize,
+}
+
+impl fmt::Display for LengthError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected an iterator of at least {} elements but it yielded {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl core::error::Error for LengthError {}
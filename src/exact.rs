@@ -0,0 +1,74 @@
+use core::iter::FusedIterator;
+#[cfg(feature = "nightly_features")]
+use core::iter::TrustedLen;
+
+/// Iterator returned by [`IntoIterator::into_iter`] on an [`IteratorFixed`](crate::IteratorFixed)
+///
+/// Keeps track of how many of the `N` elements are left, so [`Iterator::size_hint`] is exact
+/// no matter what the inner iterator reports.
+///
+/// Basic usage:
+/// ```
+/// let mut it = iter_fixed::from_fn::<_, _, 3>(|i| i).into_iter();
+///
+/// assert_eq!(it.len(), 3);
+/// it.next();
+/// assert_eq!(it.size_hint(), (2, Some(2)));
+/// ```
+#[derive(Clone)]
+pub struct Exact<I, const N: usize> {
+    pub(crate) inner: I,
+    pub(crate) remaining: usize,
+}
+
+impl<I: Iterator, const N: usize> Iterator for Exact<I, N> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // Only counted once the inner iterator has returned, so the length stays correct if it
+        // panics without losing an element
+        let next = self.inner.next();
+        self.remaining -= 1;
+        next
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.inner.take(self.remaining).fold(init, f)
+    }
+}
+
+impl<I: DoubleEndedIterator, const N: usize> DoubleEndedIterator for Exact<I, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let next = self.inner.next_back();
+        self.remaining -= 1;
+        next
+    }
+}
+
+impl<I: Iterator, const N: usize> ExactSizeIterator for Exact<I, N> {}
+
+impl<I: Iterator, const N: usize> FusedIterator for Exact<I, N> {}
+
+// Safety: An Exact is only created from an IteratorFixed whose inner iterator yields exactly
+// N elements, and `remaining` counts down the ones that are left. Clones of the inner
+// iterator yield N elements too as required by `IteratorFixed::from_iter`, so a cloned Exact
+// yields exactly `remaining` elements as well
+#[cfg(feature = "nightly_features")]
+unsafe impl<I: Iterator, const N: usize> TrustedLen for Exact<I, N> {}
@@ -0,0 +1,684 @@
+#[cfg(feature = "nightly_features")]
+use crate::helpers::{ceiling_div, max, min, sub_or_zero};
+use crate::{
+    Buckets, Either, EitherOrBoth, FillFromIteratorFixed, FromIteratorFixed, IntoIteratorFixed,
+    IteratorFixed, KeyFn, LengthError,
+};
+use core::{cmp::Ordering, mem::MaybeUninit};
+
+#[cfg(feature = "alloc")]
+use crate::{BoxedFixed, BoxedFixedSend};
+
+/// An interface for iterators of fixed size.
+///
+/// [`IteratorFixed`] is the main implementor of this trait. Implementing it for your own
+/// types only requires [`FixedIterator::into_fixed`], which builds an [`IteratorFixed`] out
+/// of the existing adapters. That makes it safe to implement, and in return all adapters of
+/// [`IteratorFixed`] can be chained directly onto your type. `rev` and `boxed_send` buffer the
+/// elements in an array first, since the iterator returned by `into_fixed` is not known to be
+/// double ended or [`Send`]. To pass it
+/// where an [`IntoIteratorFixed`] is expected, like the argument of
+/// [`IteratorFixed::zip`], call [`FixedIterator::into_fixed`] first.
+///
+/// Basic usage:
+/// ```
+/// use iter_fixed::{FixedIterator, IntoIteratorFixed, IteratorFixed};
+///
+/// /// Converts quantized samples back to floating point
+/// struct Dequantize<const N: usize> {
+///     samples: [i16; N],
+///     scale: f32,
+/// }
+///
+/// impl<const N: usize> FixedIterator<N> for Dequantize<N> {
+///     type Item = f32;
+///
+///     fn into_fixed(self) -> IteratorFixed<impl Iterator<Item = f32>, N> {
+///         let scale = self.scale;
+///         self.samples
+///             .into_iter_fixed()
+///             .map(move |x| f32::from(x) * scale)
+///     }
+/// }
+///
+/// let samples = Dequantize {
+///     samples: [2, -4, 6],
+///     scale: 0.5,
+/// };
+/// let a: [f32; 3] = samples.zip([1.0, 1.0, 1.0]).map(|(a, b)| a + b).collect();
+///
+/// assert_eq!(a, [2.0, -1.0, 4.0]);
+///
+/// let samples = Dequantize {
+///     samples: [2, -4, 6],
+///     scale: 0.5,
+/// };
+/// let b: [f32; 3] = [1.0, 1.0, 1.0]
+///     .into_iter_fixed()
+///     .zip(samples.into_fixed())
+///     .map(|(a, b)| a * b)
+///     .collect();
+///
+/// assert_eq!(b, [1.0, -2.0, 3.0]);
+/// ```
+///
+/// It can also be used to write functions that accept any iterator of fixed size:
+/// ```
+/// use iter_fixed::{FixedIterator, IntoIteratorFixed};
+///
+/// fn sum<const N: usize>(values: impl FixedIterator<N, Item = i32>) -> i32 {
+///     values.into_fixed().into_iter().sum()
+/// }
+///
+/// assert_eq!(sum([1, 2, 3].into_iter_fixed().map(|x| 2 * x)), 12);
+/// ```
+pub trait FixedIterator<const N: usize>: Sized {
+    /// The type of the elements being iterated over.
+    type Item;
+
+    /// Turns `self` into an [`IteratorFixed`] of the same elements.
+    fn into_fixed(self) -> IteratorFixed<impl Iterator<Item = Self::Item>, N>;
+
+    /// See [`IteratorFixed::map`]
+    #[inline]
+    fn map<U, F: FnMut(Self::Item) -> U>(self, f: F) -> IteratorFixed<impl Iterator<Item = U>, N> {
+        self.into_fixed().map(f)
+    }
+
+    /// See [`IteratorFixed::inspect`]
+    #[inline]
+    fn inspect<F: FnMut(&Self::Item)>(
+        self,
+        f: F,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, N> {
+        self.into_fixed().inspect(f)
+    }
+
+    /// See [`IteratorFixed::skip`]
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    fn skip<const SKIP: usize>(
+        self,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, { sub_or_zero(N, SKIP) }> {
+        self.into_fixed().skip::<SKIP>()
+    }
+
+    /// See [`IteratorFixed::step_by`]
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    fn step_by<const STEP: usize>(
+        self,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, { ceiling_div(N, STEP) }> {
+        self.into_fixed().step_by::<STEP>()
+    }
+
+    /// See [`IteratorFixed::chain`]
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    fn chain<IIF, const M: usize>(
+        self,
+        other: IIF,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, { N + M }>
+    where
+        IIF: IntoIteratorFixed<M, Item = Self::Item>,
+    {
+        self.into_fixed().chain(other)
+    }
+
+    /// See [`IteratorFixed::merge`]
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    fn merge<IIF, const M: usize>(
+        self,
+        other: IIF,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, { N + M }>
+    where
+        Self::Item: Ord,
+        IIF: IntoIteratorFixed<M, Item = Self::Item>,
+    {
+        self.into_fixed().merge(other)
+    }
+
+    /// See [`IteratorFixed::merge_by`]
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    fn merge_by<IIF, F, const M: usize>(
+        self,
+        other: IIF,
+        compare: F,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, { N + M }>
+    where
+        IIF: IntoIteratorFixed<M, Item = Self::Item>,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        self.into_fixed().merge_by(other, compare)
+    }
+
+    /// See [`IteratorFixed::merge_exact`]
+    #[inline]
+    fn merge_exact<const M: usize, const L: usize>(
+        self,
+        other: impl IntoIteratorFixed<M, Item = Self::Item>,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, L>
+    where
+        Self::Item: Ord,
+    {
+        self.into_fixed().merge_exact(other)
+    }
+
+    /// See [`IteratorFixed::merge_by_exact`]
+    #[inline]
+    fn merge_by_exact<const M: usize, const L: usize>(
+        self,
+        other: impl IntoIteratorFixed<M, Item = Self::Item>,
+        compare: impl FnMut(&Self::Item, &Self::Item) -> Ordering,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, L> {
+        self.into_fixed().merge_by_exact(other, compare)
+    }
+
+    /// See [`IteratorFixed::insert`]
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    fn insert<const INDEX: usize>(
+        self,
+        item: Self::Item,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, { N + 1 }> {
+        self.into_fixed().insert::<INDEX>(item)
+    }
+
+    /// See [`IteratorFixed::insert_exact`]
+    #[inline]
+    fn insert_exact<const INDEX: usize, const L: usize>(
+        self,
+        item: Self::Item,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, L> {
+        self.into_fixed().insert_exact::<INDEX, L>(item)
+    }
+
+    /// See [`IteratorFixed::remove`]
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    fn remove<const INDEX: usize>(
+        self,
+    ) -> (
+        Self::Item,
+        IteratorFixed<impl Iterator<Item = Self::Item>, { N - 1 }>,
+    ) {
+        self.into_fixed().remove::<INDEX>()
+    }
+
+    /// See [`IteratorFixed::remove_exact`]
+    #[inline]
+    fn remove_exact<const INDEX: usize, const L: usize>(
+        self,
+    ) -> (
+        Self::Item,
+        IteratorFixed<impl Iterator<Item = Self::Item>, L>,
+    ) {
+        self.into_fixed().remove_exact::<INDEX, L>()
+    }
+
+    /// See [`IteratorFixed::replace`]
+    #[inline]
+    fn replace<const INDEX: usize>(
+        self,
+        item: Self::Item,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, N> {
+        self.into_fixed().replace::<INDEX>(item)
+    }
+
+    /// See [`IteratorFixed::swap`]
+    #[inline]
+    fn swap<const A: usize, const B: usize>(
+        self,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, N> {
+        self.into_fixed().swap::<A, B>()
+    }
+
+    /// See [`IteratorFixed::split_at`]
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    fn split_at<const K: usize>(
+        self,
+    ) -> (
+        IteratorFixed<impl Iterator<Item = Self::Item>, K>,
+        IteratorFixed<impl Iterator<Item = Self::Item>, { N - K }>,
+    ) {
+        self.into_fixed().split_at::<K>()
+    }
+
+    /// See [`IteratorFixed::split_at_exact`]
+    #[inline]
+    fn split_at_exact<const K: usize, const R: usize>(
+        self,
+    ) -> (
+        IteratorFixed<impl Iterator<Item = Self::Item>, K>,
+        IteratorFixed<impl Iterator<Item = Self::Item>, R>,
+    ) {
+        self.into_fixed().split_at_exact::<K, R>()
+    }
+
+    /// See [`IteratorFixed::rotate_left`]
+    #[inline]
+    fn rotate_left<const K: usize>(self) -> IteratorFixed<impl Iterator<Item = Self::Item>, N> {
+        self.into_fixed().rotate_left::<K>()
+    }
+
+    /// See [`IteratorFixed::rotate_right`]
+    #[inline]
+    fn rotate_right<const K: usize>(self) -> IteratorFixed<impl Iterator<Item = Self::Item>, N> {
+        self.into_fixed().rotate_right::<K>()
+    }
+
+    /// See [`IteratorFixed::enumerate`]
+    #[inline]
+    fn enumerate(self) -> IteratorFixed<impl Iterator<Item = (usize, Self::Item)>, N> {
+        self.into_fixed().enumerate()
+    }
+
+    /// See [`IteratorFixed::take`]
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    fn take<const TAKE: usize>(
+        self,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, { min(TAKE, N) }> {
+        self.into_fixed().take::<TAKE>()
+    }
+
+    /// See [`IteratorFixed::zip`]
+    #[inline]
+    fn zip<IIF>(self, other: IIF) -> IteratorFixed<impl Iterator<Item = (Self::Item, IIF::Item)>, N>
+    where
+        IIF: IntoIteratorFixed<N>,
+    {
+        self.into_fixed().zip(other)
+    }
+
+    /// See [`IteratorFixed::zip_longest`]
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    fn zip_longest<IIF, const M: usize>(
+        self,
+        other: IIF,
+    ) -> IteratorFixed<impl Iterator<Item = EitherOrBoth<Self::Item, IIF::Item>>, { max(N, M) }>
+    where
+        IIF: IntoIteratorFixed<M>,
+    {
+        self.into_fixed().zip_longest(other)
+    }
+
+    /// See [`IteratorFixed::zip_longest_exact`]
+    #[inline]
+    fn zip_longest_exact<IIF, const M: usize, const L: usize>(
+        self,
+        other: IIF,
+    ) -> IteratorFixed<impl Iterator<Item = EitherOrBoth<Self::Item, IIF::Item>>, L>
+    where
+        IIF: IntoIteratorFixed<M>,
+    {
+        self.into_fixed().zip_longest_exact(other)
+    }
+
+    /// See [`IteratorFixed::zip_shortest`]
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    fn zip_shortest<IIF, const M: usize>(
+        self,
+        other: IIF,
+    ) -> IteratorFixed<impl Iterator<Item = (Self::Item, IIF::Item)>, { min(N, M) }>
+    where
+        IIF: IntoIteratorFixed<M>,
+    {
+        self.into_fixed().zip_shortest(other)
+    }
+
+    /// See [`IteratorFixed::zip_shortest_exact`]
+    #[inline]
+    fn zip_shortest_exact<IIF, const M: usize, const L: usize>(
+        self,
+        other: IIF,
+    ) -> IteratorFixed<impl Iterator<Item = (Self::Item, IIF::Item)>, L>
+    where
+        IIF: IntoIteratorFixed<M>,
+    {
+        self.into_fixed().zip_shortest_exact(other)
+    }
+
+    /// See [`IteratorFixed::zip_padded`]
+    #[inline]
+    fn zip_padded<II>(
+        self,
+        iter: II,
+        fill: II::Item,
+    ) -> IteratorFixed<impl Iterator<Item = (Self::Item, II::Item)>, N>
+    where
+        II: IntoIterator,
+        II::Item: Clone,
+    {
+        self.into_fixed().zip_padded(iter, fill)
+    }
+
+    /// See [`IteratorFixed::zip_checked`]
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn zip_checked<II>(
+        self,
+        iter: II,
+    ) -> Result<IteratorFixed<impl Iterator<Item = (Self::Item, II::Item)>, N>, LengthError>
+    where
+        II: IntoIterator,
+    {
+        self.into_fixed().zip_checked(iter)
+    }
+
+    /// See [`IteratorFixed::select`]
+    #[inline]
+    fn select(
+        self,
+        mask: impl IntoIteratorFixed<N, Item = bool>,
+        other: impl IntoIteratorFixed<N, Item = Self::Item>,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, N> {
+        self.into_fixed().select(mask, other)
+    }
+
+    /// See [`IteratorFixed::blend_with`]
+    #[inline]
+    fn blend_with<IIF, U, F>(self, other: IIF, f: F) -> IteratorFixed<impl Iterator<Item = U>, N>
+    where
+        IIF: IntoIteratorFixed<N>,
+        F: FnMut(usize, Self::Item, IIF::Item) -> U,
+    {
+        self.into_fixed().blend_with(other, f)
+    }
+
+    /// See [`IteratorFixed::left_fixed`]
+    #[inline]
+    fn left_fixed<R>(self) -> IteratorFixed<Either<impl Iterator<Item = Self::Item>, R>, N>
+    where
+        R: Iterator<Item = Self::Item>,
+    {
+        self.into_fixed().left_fixed()
+    }
+
+    /// See [`IteratorFixed::right_fixed`]
+    #[inline]
+    fn right_fixed<L>(self) -> IteratorFixed<Either<L, impl Iterator<Item = Self::Item>>, N>
+    where
+        L: Iterator<Item = Self::Item>,
+    {
+        self.into_fixed().right_fixed()
+    }
+
+    /// See [`IteratorFixed::boxed`]
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn boxed<'a>(self) -> BoxedFixed<'a, Self::Item, N>
+    where
+        Self: 'a,
+    {
+        self.into_fixed().boxed()
+    }
+
+    /// See [`IteratorFixed::boxed_send`]
+    ///
+    /// The elements are buffered in an array first, since the iterator returned by
+    /// [`FixedIterator::into_fixed`] is not known to be [`Send`]. This means that the adapters
+    /// of `self` run when `boxed_send` is called, not when the result is iterated.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn boxed_send<'a>(self) -> BoxedFixedSend<'a, Self::Item, N>
+    where
+        Self::Item: Send + 'a,
+    {
+        let buffer: [Self::Item; N] = self.collect();
+        buffer.into_iter_fixed().boxed_send()
+    }
+
+    /// See [`IteratorFixed::rev`]
+    ///
+    /// The elements are buffered in an array first, since the iterator returned by
+    /// [`FixedIterator::into_fixed`] is not known to be double ended.
+    #[inline]
+    fn rev(self) -> IteratorFixed<impl Iterator<Item = Self::Item>, N> {
+        let buffer: [Self::Item; N] = self.collect();
+        buffer.into_iter_fixed().rev()
+    }
+
+    /// See [`IteratorFixed::flatten`]
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    fn flatten<IIF, const M: usize>(
+        self,
+    ) -> IteratorFixed<impl Iterator<Item = IIF::Item>, { M * N }>
+    where
+        Self: FixedIterator<N, Item = IIF>,
+        IIF: IntoIteratorFixed<M>,
+    {
+        self.into_fixed().flatten()
+    }
+
+    /// See [`IteratorFixed::flat_map`]
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    fn flat_map<F, IIF, const M: usize>(
+        self,
+        f: F,
+    ) -> IteratorFixed<impl Iterator<Item = IIF::Item>, { M * N }>
+    where
+        F: FnMut(Self::Item) -> IIF,
+        IIF: IntoIteratorFixed<M>,
+    {
+        self.into_fixed().flat_map(f)
+    }
+
+    /// See [`IteratorFixed::sorted`]
+    #[inline]
+    fn sorted(self) -> IteratorFixed<impl Iterator<Item = Self::Item>, N>
+    where
+        Self::Item: Ord,
+    {
+        self.into_fixed().sorted()
+    }
+
+    /// See [`IteratorFixed::sorted_by`]
+    #[inline]
+    fn sorted_by<F>(self, compare: F) -> IteratorFixed<impl Iterator<Item = Self::Item>, N>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        self.into_fixed().sorted_by(compare)
+    }
+
+    /// See [`IteratorFixed::sorted_by_key`]
+    #[inline]
+    fn sorted_by_key<K, F>(self, f: F) -> IteratorFixed<impl Iterator<Item = Self::Item>, N>
+    where
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.into_fixed().sorted_by_key(f)
+    }
+
+    /// See [`IteratorFixed::argsort`]
+    #[inline]
+    fn argsort(self) -> IteratorFixed<impl Iterator<Item = usize>, N>
+    where
+        Self::Item: Ord,
+    {
+        self.into_fixed().argsort()
+    }
+
+    /// See [`IteratorFixed::argsort_by_key`]
+    #[inline]
+    fn argsort_by_key<K, F>(self, f: F) -> IteratorFixed<impl Iterator<Item = usize>, N>
+    where
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.into_fixed().argsort_by_key(f)
+    }
+
+    /// See [`IteratorFixed::rank`]
+    #[inline]
+    fn rank(self) -> IteratorFixed<impl Iterator<Item = usize>, N>
+    where
+        Self::Item: Ord,
+    {
+        self.into_fixed().rank()
+    }
+
+    /// See [`IteratorFixed::top_k`]
+    #[inline]
+    fn top_k<const K: usize>(self) -> IteratorFixed<impl Iterator<Item = Self::Item>, K>
+    where
+        Self::Item: Ord,
+    {
+        self.into_fixed().top_k::<K>()
+    }
+
+    /// See [`IteratorFixed::top_k_by_key`]
+    #[inline]
+    fn top_k_by_key<const K: usize>(
+        self,
+        f: impl KeyFn<Self::Item>,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, K> {
+        self.into_fixed().top_k_by_key::<K>(f)
+    }
+
+    /// See [`IteratorFixed::bottom_k`]
+    #[inline]
+    fn bottom_k<const K: usize>(self) -> IteratorFixed<impl Iterator<Item = Self::Item>, K>
+    where
+        Self::Item: Ord,
+    {
+        self.into_fixed().bottom_k::<K>()
+    }
+
+    /// See [`IteratorFixed::bottom_k_by_key`]
+    #[inline]
+    fn bottom_k_by_key<const K: usize>(
+        self,
+        f: impl KeyFn<Self::Item>,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, K> {
+        self.into_fixed().bottom_k_by_key::<K>(f)
+    }
+
+    /// See [`IteratorFixed::gather`]
+    #[inline]
+    fn gather<const M: usize>(
+        self,
+        indices: impl IntoIteratorFixed<M, Item = usize>,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, M>
+    where
+        Self::Item: Clone,
+    {
+        self.into_fixed().gather(indices)
+    }
+
+    /// See [`IteratorFixed::permute`]
+    #[inline]
+    fn permute(
+        self,
+        permutation: impl IntoIteratorFixed<N, Item = usize>,
+    ) -> IteratorFixed<impl Iterator<Item = Self::Item>, N> {
+        self.into_fixed().permute(permutation)
+    }
+
+    /// See [`IteratorFixed::scatter_into`]
+    #[inline]
+    fn scatter_into<const M: usize>(
+        self,
+        dest: &mut [Self::Item; M],
+        indices: impl IntoIteratorFixed<N, Item = usize>,
+    ) {
+        self.into_fixed().scatter_into(dest, indices)
+    }
+
+    /// See [`IteratorFixed::histogram`]
+    #[inline]
+    fn histogram<const K: usize>(
+        self,
+        bucket: impl FnMut(&Self::Item) -> usize,
+    ) -> Buckets<usize, K> {
+        self.into_fixed().histogram::<K>(bucket)
+    }
+
+    /// See [`IteratorFixed::group_into`]
+    #[inline]
+    fn group_into<const K: usize, C: Default>(
+        self,
+        bucket: impl FnMut(&Self::Item) -> usize,
+        fold: impl FnMut(&mut C, Self::Item),
+    ) -> Buckets<C, K> {
+        self.into_fixed().group_into::<K, C>(bucket, fold)
+    }
+
+    /// See [`IteratorFixed::collect`]
+    #[inline]
+    fn collect<U: FromIteratorFixed<Self::Item, N>>(self) -> U {
+        self.into_fixed().collect()
+    }
+
+    /// See [`IteratorFixed::collect_into`]
+    #[inline]
+    fn collect_into<U>(self, dest: &mut U) -> &mut U
+    where
+        U: FillFromIteratorFixed<Self::Item, N>,
+    {
+        self.into_fixed().collect_into(dest)
+    }
+
+    /// See [`IteratorFixed::write_uninit`]
+    #[inline]
+    fn write_uninit(self, dest: &mut [MaybeUninit<Self::Item>; N]) -> &mut [Self::Item; N] {
+        self.into_fixed().write_uninit(dest)
+    }
+
+    /// See [`IteratorFixed::count_ones`]
+    #[inline]
+    fn count_ones(self) -> usize
+    where
+        Self: FixedIterator<N, Item = bool>,
+    {
+        self.into_fixed().count_ones()
+    }
+
+    /// See [`IteratorFixed::count_zeros`]
+    #[inline]
+    fn count_zeros(self) -> usize
+    where
+        Self: FixedIterator<N, Item = bool>,
+    {
+        self.into_fixed().count_zeros()
+    }
+
+    /// See [`IteratorFixed::copied`]
+    #[inline]
+    fn copied<'a, T>(self) -> IteratorFixed<impl Iterator<Item = T>, N>
+    where
+        Self: FixedIterator<N, Item = &'a T>,
+        T: 'a + Copy,
+    {
+        self.into_fixed().copied()
+    }
+
+    /// See [`IteratorFixed::cloned`]
+    #[inline]
+    fn cloned<'a, T>(self) -> IteratorFixed<impl Iterator<Item = T>, N>
+    where
+        Self: FixedIterator<N, Item = &'a T>,
+        T: 'a + Clone,
+    {
+        self.into_fixed().cloned()
+    }
+}
+
+impl<I: Iterator, const N: usize> FixedIterator<N> for IteratorFixed<I, N> {
+    type Item = I::Item;
+
+    /// `IteratorFixed` implements `FixedIterator` as a no op.
+    #[inline]
+    fn into_fixed(self) -> IteratorFixed<impl Iterator<Item = I::Item>, N> {
+        self
+    }
+}
@@ -1,5 +1,13 @@
+use crate::helpers::{for_each_tuple, tuple_element};
 use crate::IteratorFixed;
 
+use crate::uninit::collect_array;
+#[cfg(feature = "alloc")]
+use crate::uninit::write_slots;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, rc::Rc, sync::Arc, vec::Vec};
+
 /// Conversion from an [`IteratorFixed`].
 ///
 /// By implementing `FromIteratorFixed` for a type, you define how it will be
@@ -10,6 +18,26 @@ use crate::IteratorFixed;
 /// documentation for more examples.
 ///
 /// See also: [`crate::IntoIteratorFixed`].
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be built from a fixed size iterator of {N} elements of type `{T}`",
+    label = "the iterator has {N} elements",
+    note = "arrays and tuples only implement `FromIteratorFixed` for their own length and \
+            element type, `Vec`, `Box`, `Rc` and `Arc` require the `alloc` feature"
+)]
+#[cfg_attr(
+    not(feature = "nightly_features"),
+    diagnostic::on_unimplemented(
+        note = "if only the length differs, it can be changed with `split_at_exact` or \
+                `zip_shortest_exact`"
+    )
+)]
+#[cfg_attr(
+    feature = "nightly_features",
+    diagnostic::on_unimplemented(
+        note = "if only the length differs, it can be changed with `take`, `skip`, \
+                `split_at_exact` or `zip_shortest_exact`"
+    )
+)]
 pub trait FromIteratorFixed<T, const N: usize> {
     /// Creates a value from a fixed size iterator.
     ///
@@ -35,6 +63,41 @@ pub trait FromIteratorFixed<T, const N: usize> {
     fn from_iter_fixed<I: Iterator<Item = T>>(iter_fixed: IteratorFixed<I, N>) -> Self;
 }
 
+/// Filling existing storage from an [`IteratorFixed`].
+///
+/// This is the in place counterpart of [`FromIteratorFixed`]. By implementing
+/// `FillFromIteratorFixed` for a type, you define how its current contents are replaced by
+/// the elements of an iterator of fixed size.
+///
+/// [`FillFromIteratorFixed::fill_from_iter_fixed()`] is rarely called explicitly, and is
+/// instead used through [`IteratorFixed::collect_into()`] method.
+pub trait FillFromIteratorFixed<T, const N: usize> {
+    /// Replaces the contents of `self` with the elements of a fixed size iterator.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::{FillFromIteratorFixed, IntoIteratorFixed};
+    ///
+    /// let mut a = [0; 3];
+    /// a.fill_from_iter_fixed([1, 2, 3].into_iter_fixed().map(|x| 2 * x));
+    ///
+    /// assert_eq!(a, [2, 4, 6]);
+    /// ```
+    fn fill_from_iter_fixed<I: Iterator<Item = T>>(&mut self, iter_fixed: IteratorFixed<I, N>);
+}
+
+impl<T, const N: usize> FillFromIteratorFixed<T, N> for [T; N] {
+    /// Assigns the elements of a fixed size iterator to an array, dropping the old elements.
+    ///
+    /// If the iterator panics, the elements assigned so far keep their new values.
+    #[inline]
+    fn fill_from_iter_fixed<I: Iterator<Item = T>>(&mut self, iter_fixed: IteratorFixed<I, N>) {
+        for (slot, x) in self.iter_mut().zip(iter_fixed.inner) {
+            *slot = x;
+        }
+    }
+}
+
 impl<T, const N: usize> FromIteratorFixed<T, N> for [T; N] {
     /// Creates an array from a fixed size iterator.
     ///
@@ -57,11 +120,165 @@ impl<T, const N: usize> FromIteratorFixed<T, N> for [T; N] {
     /// let a: [i32; 3] = two_four_six.collect();
     /// assert_eq!(a, [2, 4, 6]);
     /// ```
+    ///
+    /// With `nightly_features`, iterators where every stage can produce its elements by index,
+    /// like `zip`, `map` and `enumerate` over arrays of `Copy` elements, references to arrays
+    /// and [`crate::from_fn`], are collected with `core::array::from_fn` instead of calling
+    /// `next()` for every element. This gives the same index based code as hand written array
+    /// loops. On stable, and for every other iterator, the elements are collected in order.
+    ///
+    /// If the iterator panics, the elements yielded so far are dropped.
+    #[inline]
+    fn from_iter_fixed<I: Iterator<Item = T>>(iter_fixed: IteratorFixed<I, N>) -> Self {
+        collect_array(iter_fixed)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> FromIteratorFixed<T, N> for Vec<T> {
+    /// Creates a vector with a capacity of exactly `N` from a fixed size iterator.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let v: Vec<i32> = [1, 2, 3].into_iter_fixed().map(|x| 2 * x).collect();
+    ///
+    /// assert_eq!(v, [2, 4, 6]);
+    /// assert_eq!(v.capacity(), 3);
+    /// ```
     #[inline]
     fn from_iter_fixed<I: Iterator<Item = T>>(iter_fixed: IteratorFixed<I, N>) -> Self {
-        let IteratorFixed { inner: mut it } = iter_fixed;
-        // We know that it will yield N elements due to it originating from an IteratorFixed
-        // of size N
-        [(); N].map(|()| it.next().unwrap())
+        let mut vec = Self::with_capacity(N);
+        vec.extend(iter_fixed.inner);
+        vec
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> FromIteratorFixed<T, N> for Box<[T]> {
+    /// Creates a boxed slice from a fixed size iterator, writing the elements directly into
+    /// the heap allocation.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let b: Box<[i32]> = [1, 2, 3].into_iter_fixed().map(|x| 2 * x).collect();
+    ///
+    /// assert_eq!(*b, [2, 4, 6]);
+    /// ```
+    #[inline]
+    fn from_iter_fixed<I: Iterator<Item = T>>(iter_fixed: IteratorFixed<I, N>) -> Self {
+        let mut boxed = Self::new_uninit_slice(N);
+        write_slots(&mut boxed, iter_fixed);
+
+        // Safety: We know that the iterator yielded N elements due to it originating from an
+        // IteratorFixed of size N, so all N slots have been initialized
+        unsafe { boxed.assume_init() }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> FromIteratorFixed<T, N> for Box<[T; N]> {
+    /// Creates a boxed array from a fixed size iterator, writing the elements directly into
+    /// the heap allocation.
+    ///
+    /// This allows collecting arrays that are too large for the stack.
+    ///
+    /// Basic usage:
+    /// ```
+    /// let table: Box<[u32; 1 << 20]> = iter_fixed::from_fn(|i| i as u32 * 3).collect();
+    ///
+    /// assert_eq!(table[1000], 3000);
+    /// ```
+    #[inline]
+    fn from_iter_fixed<I: Iterator<Item = T>>(iter_fixed: IteratorFixed<I, N>) -> Self {
+        let boxed: Box<[T]> = Box::from_iter_fixed(iter_fixed);
+
+        // Safety: `boxed` has exactly N elements
+        unsafe { Self::from_raw(Box::into_raw(boxed).cast::<[T; N]>()) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> FromIteratorFixed<T, N> for Rc<[T]> {
+    /// Creates a reference counted slice from a fixed size iterator, writing the elements
+    /// directly into the heap allocation.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use std::rc::Rc;
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let rc: Rc<[i32]> = [1, 2, 3].into_iter_fixed().map(|x| 2 * x).collect();
+    ///
+    /// assert_eq!(*rc, [2, 4, 6]);
+    /// ```
+    #[inline]
+    fn from_iter_fixed<I: Iterator<Item = T>>(iter_fixed: IteratorFixed<I, N>) -> Self {
+        let mut rc = Self::new_uninit_slice(N);
+        // The Rc has just been created so there are no other references to it
+        write_slots(Rc::get_mut(&mut rc).unwrap(), iter_fixed);
+
+        // Safety: We know that the iterator yielded N elements due to it originating from an
+        // IteratorFixed of size N, so all N slots have been initialized
+        unsafe { rc.assume_init() }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> FromIteratorFixed<T, N> for Arc<[T]> {
+    /// Creates an atomically reference counted slice from a fixed size iterator, writing the
+    /// elements directly into the heap allocation.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use std::sync::Arc;
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let arc: Arc<[i32]> = [1, 2, 3].into_iter_fixed().map(|x| 2 * x).collect();
+    ///
+    /// assert_eq!(*arc, [2, 4, 6]);
+    /// ```
+    #[inline]
+    fn from_iter_fixed<I: Iterator<Item = T>>(iter_fixed: IteratorFixed<I, N>) -> Self {
+        let mut arc = Self::new_uninit_slice(N);
+        // The Arc has just been created so there are no other references to it
+        write_slots(Arc::get_mut(&mut arc).unwrap(), iter_fixed);
+
+        // Safety: We know that the iterator yielded N elements due to it originating from an
+        // IteratorFixed of size N, so all N slots have been initialized
+        unsafe { arc.assume_init() }
+    }
+}
+
+macro_rules! impl_from_iter_fixed_for_tuple {
+    ($n:literal; $($x:ident)+) => {
+        impl<T> FromIteratorFixed<T, $n> for ($(tuple_element!($x, T),)+) {
+            /// Creates a homogeneous tuple from a fixed size iterator.
+            #[inline]
+            fn from_iter_fixed<I: Iterator<Item = T>>(iter_fixed: IteratorFixed<I, $n>) -> Self {
+                let [$($x),+] = <[T; $n]>::from_iter_fixed(iter_fixed);
+                ($($x,)+)
+            }
+        }
+
+        impl<T> FillFromIteratorFixed<T, $n> for ($(tuple_element!($x, T),)+) {
+            /// Assigns the elements of a fixed size iterator to a homogeneous tuple, dropping
+            /// the old elements.
+            #[inline]
+            fn fill_from_iter_fixed<I: Iterator<Item = T>>(
+                &mut self,
+                iter_fixed: IteratorFixed<I, $n>,
+            ) {
+                let ($($x,)+) = self;
+                for (slot, x) in [$($x),+].into_iter().zip(iter_fixed.inner) {
+                    *slot = x;
+                }
+            }
+        }
+    };
+}
+
+for_each_tuple!(impl_from_iter_fixed_for_tuple);
@@ -1,4 +1,3 @@
-#[cfg(feature = "nightly_features")]
 pub const fn min(a: usize, b: usize) -> usize {
     if a < b {
         a
@@ -7,6 +6,14 @@ pub const fn min(a: usize, b: usize) -> usize {
     }
 }
 
+pub const fn max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
 #[cfg(feature = "nightly_features")]
 pub const fn sub_or_zero(a: usize, b: usize) -> usize {
     if a > b {
@@ -20,3 +27,56 @@ pub const fn sub_or_zero(a: usize, b: usize) -> usize {
 pub const fn ceiling_div(x: usize, d: usize) -> usize {
     x / d + (x % d != 0) as usize
 }
+
+pub const fn same_size<T, U>() -> bool {
+    core::mem::size_of::<T>() == core::mem::size_of::<U>()
+}
+
+pub const fn same_align<T, U>() -> bool {
+    core::mem::align_of::<T>() == core::mem::align_of::<U>()
+}
+
+/// Panics with `msg` unless `cond` holds, used as `const { const_assert(..) }` to check
+/// const generic parameters at compile time
+pub const fn const_assert(cond: bool, msg: &str) {
+    if !cond {
+        panic!("{}", msg)
+    }
+}
+
+/// `x % d`, or 0 when `d` is 0
+pub const fn rem_or_zero(x: usize, d: usize) -> usize {
+    if d == 0 {
+        0
+    } else {
+        x % d
+    }
+}
+
+/// Invokes `$m!(len; names...)` for every tuple arity that has conversions to and from
+/// fixed size iterators, with one name per tuple element
+macro_rules! for_each_tuple {
+    ($m:ident) => {
+        $m!(1; a);
+        $m!(2; a b);
+        $m!(3; a b c);
+        $m!(4; a b c d);
+        $m!(5; a b c d e);
+        $m!(6; a b c d e f);
+        $m!(7; a b c d e f g);
+        $m!(8; a b c d e f g h);
+        $m!(9; a b c d e f g h i);
+        $m!(10; a b c d e f g h i j);
+        $m!(11; a b c d e f g h i j k);
+        $m!(12; a b c d e f g h i j k l);
+    };
+}
+pub(crate) use for_each_tuple;
+
+/// Expands to `$t` for every element name `$x`, used for spelling out homogeneous tuples
+macro_rules! tuple_element {
+    ($x:ident, $t:ty) => {
+        $t
+    };
+}
+pub(crate) use tuple_element;
@@ -1,7 +1,13 @@
+use crate::helpers::{for_each_tuple, tuple_element};
 use crate::IteratorFixed;
 
 use core::{array, iter, slice};
 
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use core::{iter::FusedIterator, mem::MaybeUninit, ops::Range, ptr};
+
 /// Conversion into an [`IteratorFixed`].
 ///
 /// By implementing `IntoIteratorFixed` for a type, you define how it will be
@@ -10,7 +16,28 @@ use core::{array, iter, slice};
 /// See also: [`crate::FromIteratorFixed`].
 ///
 /// # Safety
-/// Implementer has to guarantee that the inner iterator will always yield exactly N elements
+/// Implementer has to guarantee that the inner iterator will always yield exactly N elements,
+/// and so will every clone of it, see [`IteratorFixed::from_iter`]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a fixed size iterator of length {N}",
+    label = "expected something of length {N}",
+    note = "arrays and `IteratorFixed` only implement `IntoIteratorFixed` for their own length, \
+            so both sides need to have the same length"
+)]
+#[cfg_attr(
+    not(feature = "nightly_features"),
+    diagnostic::on_unimplemented(
+        note = "the length of an `IteratorFixed` can be changed with `split_at_exact` or \
+                `zip_shortest_exact`"
+    )
+)]
+#[cfg_attr(
+    feature = "nightly_features",
+    diagnostic::on_unimplemented(
+        note = "the length of an `IteratorFixed` can be changed with `take`, `skip`, \
+                `split_at_exact` or `zip_shortest_exact`"
+    )
+)]
 pub unsafe trait IntoIteratorFixed<const N: usize> {
     /// The type of the elements being iterated over.
     type Item;
@@ -33,10 +60,7 @@ pub unsafe trait IntoIteratorFixed<const N: usize> {
 }
 
 // IteratorFixed implements IntoIteratorFixed
-unsafe impl<I: Iterator, const N: usize> IntoIteratorFixed<N> for IteratorFixed<I, N>
-where
-    Self: IntoIterator,
-{
+unsafe impl<I: Iterator, const N: usize> IntoIteratorFixed<N> for IteratorFixed<I, N> {
     type Item = I::Item;
     type IntoIter = I;
 
@@ -155,3 +179,148 @@ unsafe impl<T: Clone, const N: usize> IntoIteratorFixed<N> for iter::Repeat<T> {
         unsafe { IteratorFixed::from_iter(self.take(N)) }
     }
 }
+
+#[cfg(feature = "alloc")]
+unsafe impl<T, const N: usize> IntoIteratorFixed<N> for Box<[T; N]> {
+    type Item = T;
+    type IntoIter = BoxIntoIter<T, N>;
+
+    /// Creates a fixed size iterator from a boxed array.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let boxed = Box::new([1, 2, 3]);
+    ///
+    /// let a: [i32; 3] = boxed.into_iter_fixed().map(|x| 2 * x).collect();
+    /// assert_eq!(a, [2, 4, 6]);
+    /// ```
+    #[inline]
+    fn into_iter_fixed(self) -> IteratorFixed<BoxIntoIter<T, N>, N> {
+        // Safety: MaybeUninit<T> has the same layout as T
+        let buf = unsafe { Box::from_raw(Self::into_raw(self).cast::<[MaybeUninit<T>; N]>()) };
+        IteratorFixed {
+            inner: BoxIntoIter { buf, alive: 0..N },
+        }
+    }
+}
+
+/// Iterator moving the elements out of a boxed array, created by
+/// [`IntoIteratorFixed::into_iter_fixed`] on `Box<[T; N]>`
+///
+/// The allocation is kept until the iterator is dropped, which allows
+/// [`IteratorFixed::map_in_place`] to reuse it.
+#[cfg(feature = "alloc")]
+pub struct BoxIntoIter<T, const N: usize> {
+    pub(crate) buf: Box<[MaybeUninit<T>; N]>,
+    pub(crate) alive: Range<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> Iterator for BoxIntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let i = self.alive.next()?;
+        // Safety: Elements in `alive` are initialized and i is removed from it
+        Some(unsafe { self.buf[i].assume_init_read() })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.alive.size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> DoubleEndedIterator for BoxIntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        let i = self.alive.next_back()?;
+        // Safety: Elements in `alive` are initialized and i is removed from it
+        Some(unsafe { self.buf[i].assume_init_read() })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> ExactSizeIterator for BoxIntoIter<T, N> {}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> FusedIterator for BoxIntoIter<T, N> {}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone, const N: usize> Clone for BoxIntoIter<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        // Safety: An array of MaybeUninit does not need to be initialized
+        let mut buf = unsafe { Box::<[MaybeUninit<T>; N]>::new_uninit().assume_init() };
+        for i in self.alive.clone() {
+            // Safety: Elements in `alive` are initialized
+            buf[i].write(unsafe { self.buf[i].assume_init_ref() }.clone());
+        }
+        Self {
+            buf,
+            alive: self.alive.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> Drop for BoxIntoIter<T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        let alive = &mut self.buf[self.alive.clone()];
+        // Safety: Elements in `alive` are initialized and never read again
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                alive.as_mut_ptr().cast::<T>(),
+                alive.len(),
+            ))
+        }
+    }
+}
+
+macro_rules! impl_into_iter_fixed_for_tuple {
+    ($n:literal; $($x:ident)+) => {
+        unsafe impl<T> IntoIteratorFixed<$n> for ($(tuple_element!($x, T),)+) {
+            type Item = T;
+            type IntoIter = array::IntoIter<T, $n>;
+
+            /// Creates a fixed size iterator from a homogeneous tuple.
+            #[inline]
+            fn into_iter_fixed(self) -> IteratorFixed<Self::IntoIter, $n> {
+                let ($($x,)+) = self;
+                [$($x),+].into_iter_fixed()
+            }
+        }
+
+        unsafe impl<'a, T> IntoIteratorFixed<$n> for &'a ($(tuple_element!($x, T),)+) {
+            type Item = &'a T;
+            type IntoIter = array::IntoIter<&'a T, $n>;
+
+            /// Creates a fixed size iterator from a borrowed homogeneous tuple.
+            #[inline]
+            fn into_iter_fixed(self) -> IteratorFixed<Self::IntoIter, $n> {
+                let ($($x,)+) = self;
+                [$($x),+].into_iter_fixed()
+            }
+        }
+
+        unsafe impl<'a, T> IntoIteratorFixed<$n> for &'a mut ($(tuple_element!($x, T),)+) {
+            type Item = &'a mut T;
+            type IntoIter = array::IntoIter<&'a mut T, $n>;
+
+            /// Creates a fixed size iterator from a mutably borrowed homogeneous tuple.
+            #[inline]
+            fn into_iter_fixed(self) -> IteratorFixed<Self::IntoIter, $n> {
+                let ($($x,)+) = self;
+                [$($x),+].into_iter_fixed()
+            }
+        }
+    };
+}
+
+// Safety: all of these delegate to the array impl of the same length
+for_each_tuple!(impl_into_iter_fixed_for_tuple);
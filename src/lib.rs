@@ -45,23 +45,69 @@
 #![allow(stable_features)]
 #![cfg_attr(feature = "nightly_features", allow(incomplete_features))]
 #![cfg_attr(feature = "nightly_features", feature(generic_const_exprs))]
+#![cfg_attr(feature = "nightly_features", feature(trusted_len))]
+#![cfg_attr(feature = "nightly_features", feature(min_specialization))]
+#![cfg_attr(feature = "nightly_features", feature(trusted_random_access))]
 // enable additionnal lints
 #![warn(clippy::doc_markdown)]
 #![warn(clippy::ignored_unit_patterns)]
 #![warn(clippy::missing_inline_in_public_items)]
 #![warn(clippy::use_self)]
 
-use core::iter;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use core::{
+    array,
+    cmp::Ordering,
+    iter,
+    mem::{ManuallyDrop, MaybeUninit},
+    ptr,
+};
+
+mod buckets;
+mod drain;
+mod edit;
+mod either;
+mod error;
+mod exact;
+mod fixed_iterator;
 mod from;
 mod helpers;
 mod into;
+mod merge;
+mod random_access;
+mod range;
+mod sort;
+mod sources;
+mod uninit;
+mod zip_longest;
 
+use edit::Insert;
 #[cfg(feature = "nightly_features")]
-use helpers::{ceiling_div, min, sub_or_zero};
+use helpers::{ceiling_div, sub_or_zero};
+use helpers::{const_assert, max, min, rem_or_zero};
+use helpers::{same_align, same_size};
+use merge::Merge;
+use zip_longest::ZipLongest;
 
-pub use from::FromIteratorFixed;
+pub use buckets::Buckets;
+pub use drain::ArrayDrain;
+pub use either::Either;
+pub use error::LengthError;
+pub use exact::Exact;
+pub use fixed_iterator::FixedIterator;
+pub use from::{FillFromIteratorFixed, FromIteratorFixed};
+#[cfg(feature = "alloc")]
+pub use into::BoxIntoIter;
 pub use into::IntoIteratorFixed;
+pub use range::{range, range_const, range_step, range_step_const, Range, RangeInt};
+pub use sort::KeyFn;
+pub use sources::{empty, iterate, once, repeat_n, repeat_with, unfold, FromFn, Iterate, Unfold};
+pub use zip_longest::EitherOrBoth;
 
 /// Iterator of fixed size
 ///
@@ -71,10 +117,19 @@ pub use into::IntoIteratorFixed;
 ///
 /// Just like [`Iterator`], [`IteratorFixed`] provides a lot of methods like:
 /// - available on stable rust:  
-///   [`map`], [`inspect`], [`enumerate`], [`zip`], [`rev`], [`copied`], [`cloned`]
+///   [`map`], [`inspect`], [`enumerate`], [`zip`], [`rev`], [`copied`], [`cloned`],
+///   [`sorted`], [`argsort`], [`rank`], [`top_k`], [`bottom_k`], [`gather`], [`permute`],
+///   [`select`], [`blend_with`], [`replace`], [`swap`], [`insert_exact`], [`remove_exact`],
+///   [`split_at_exact`], [`split_array_at_exact`], [`map_in_place`], [`rotate_left`],
+///   [`rotate_right`], [`zip_longest_exact`], [`zip_shortest_exact`], [`zip_padded`],
+///   [`zip_checked`], [`collect_into`], [`write_uninit`], [`left_fixed`], [`right_fixed`]
+///     
+/// - requires the `alloc` feature:  
+///   `boxed`, `boxed_send`
 ///     
 /// - requires nightly compiler and enable `nightly_features`:  
-///   [`skip`], [`step_by`], [`chain`], [`take`], [`flatten`]
+///   [`skip`], [`step_by`], [`chain`], [`take`], [`flatten`], `insert`, `remove`,
+///   `split_at`, `zip_longest`, `zip_shortest`
 ///
 /// however it does not support methods like `filter` or `take_while` which will affect the length during runtime.
 ///
@@ -85,15 +140,52 @@ pub use into::IntoIteratorFixed;
 /// [`rev`]: IteratorFixed::rev
 /// [`copied`]: IteratorFixed::copied
 /// [`cloned`]: IteratorFixed::cloned
+/// [`sorted`]: IteratorFixed::sorted
+/// [`argsort`]: IteratorFixed::argsort
+/// [`rank`]: IteratorFixed::rank
+/// [`top_k`]: IteratorFixed::top_k
+/// [`bottom_k`]: IteratorFixed::bottom_k
+/// [`gather`]: IteratorFixed::gather
+/// [`permute`]: IteratorFixed::permute
+/// [`select`]: IteratorFixed::select
+/// [`blend_with`]: IteratorFixed::blend_with
+/// [`replace`]: IteratorFixed::replace
+/// [`swap`]: IteratorFixed::swap
+/// [`insert_exact`]: IteratorFixed::insert_exact
+/// [`remove_exact`]: IteratorFixed::remove_exact
+/// [`split_at_exact`]: IteratorFixed::split_at_exact
+/// [`split_array_at_exact`]: IteratorFixed::split_array_at_exact
+/// [`map_in_place`]: IteratorFixed::map_in_place
+/// [`rotate_left`]: IteratorFixed::rotate_left
+/// [`rotate_right`]: IteratorFixed::rotate_right
+/// [`zip_longest_exact`]: IteratorFixed::zip_longest_exact
+/// [`zip_shortest_exact`]: IteratorFixed::zip_shortest_exact
+/// [`zip_padded`]: IteratorFixed::zip_padded
+/// [`zip_checked`]: IteratorFixed::zip_checked
+/// [`collect_into`]: IteratorFixed::collect_into
+/// [`write_uninit`]: IteratorFixed::write_uninit
+/// [`left_fixed`]: IteratorFixed::left_fixed
+/// [`right_fixed`]: IteratorFixed::right_fixed
 /// [`skip`]: IteratorFixed::skip
 /// [`step_by`]: IteratorFixed::step_by
 /// [`chain`]: IteratorFixed::chain
 /// [`take`]: IteratorFixed::take
 /// [`flatten`]: IteratorFixed::flatten
+#[derive(Clone)]
 pub struct IteratorFixed<I: Iterator, const N: usize> {
     inner: I,
 }
 
+/// An [`IteratorFixed`] of `N` elements of type `T` with the adapter chain erased, see
+/// [`IteratorFixed::boxed`]
+#[cfg(feature = "alloc")]
+pub type BoxedFixed<'a, T, const N: usize> = IteratorFixed<Box<dyn Iterator<Item = T> + 'a>, N>;
+
+/// Like [`BoxedFixed`] but [`Send`], see [`IteratorFixed::boxed_send`]
+#[cfg(feature = "alloc")]
+pub type BoxedFixedSend<'a, T, const N: usize> =
+    IteratorFixed<Box<dyn Iterator<Item = T> + Send + 'a>, N>;
+
 /// Creates a new iterator of fixed size where each iteration calls the provided closure F: FnMut(usize) -> T
 ///
 /// This allows very simple initialization of types that implement [`FromIteratorFixed`] such as arrays.
@@ -102,6 +194,9 @@ pub struct IteratorFixed<I: Iterator, const N: usize> {
 /// in `IteratorFixed::from_fn` the provided function does not have any say in the number of elements.
 /// The length is entirely determined by `N`.
 ///
+/// With `nightly_features`, collecting it into an array calls `f` by index, also after `map`,
+/// `zip` and `enumerate`, see [`FromIteratorFixed`].
+///
 /// Basic usage:
 /// ```
 /// let zero_two_four: [usize; 3] = iter_fixed::from_fn(|i| 2 * i).collect();
@@ -109,16 +204,12 @@ pub struct IteratorFixed<I: Iterator, const N: usize> {
 /// assert_eq!(zero_two_four, [0, 2, 4]);
 /// ```
 #[inline]
-pub fn from_fn<'a, F, T: 'a, const N: usize>(
-    mut f: F,
-) -> IteratorFixed<impl Iterator<Item = T> + 'a, N>
+pub fn from_fn<F, T, const N: usize>(f: F) -> IteratorFixed<FromFn<F>, N>
 where
-    F: FnMut(usize) -> T + 'a,
+    F: FnMut(usize) -> T,
 {
-    [(); N]
-        .into_iter_fixed()
-        .enumerate()
-        .map(move |(i, ())| f(i))
+    // Safety: FromFn yields one element for every index in 0..N
+    unsafe { IteratorFixed::from_iter(FromFn { f, indices: 0..N }) }
 }
 
 impl<I, const N: usize> IteratorFixed<I, N>
@@ -126,7 +217,9 @@ where
     I: Iterator,
 {
     /// # Safety
-    /// Caller has to guarantee that the given iterator will yield exactly N elements
+    /// Caller has to guarantee that the given iterator will yield exactly N elements. If the
+    /// iterator implements [`Clone`], every clone of it has to yield exactly N elements as
+    /// well, since cloning the returned [`IteratorFixed`] clones the iterator.
     ///
     // TODO: Would it be ok if it generated more elements?
     #[inline]
@@ -141,7 +234,7 @@ where
     pub fn map<U, F: FnMut(<I as Iterator>::Item) -> U>(
         self,
         p: F,
-    ) -> IteratorFixed<impl Iterator<Item = U>, N> {
+    ) -> IteratorFixed<iter::Map<I, F>, N> {
         IteratorFixed {
             inner: self.inner.map(p),
         }
@@ -152,7 +245,7 @@ where
     pub fn inspect<F: FnMut(&<I as Iterator>::Item)>(
         self,
         p: F,
-    ) -> IteratorFixed<impl Iterator<Item = I::Item>, N> {
+    ) -> IteratorFixed<iter::Inspect<I, F>, N> {
         IteratorFixed {
             inner: self.inner.inspect(p),
         }
@@ -196,149 +289,1570 @@ where
         }
     }
 
-    /// See [`core::iter::Iterator::enumerate`]
-    #[inline]
-    pub fn enumerate(self) -> IteratorFixed<impl Iterator<Item = (usize, I::Item)>, N> {
-        IteratorFixed {
-            inner: self.inner.enumerate(),
-        }
-    }
-
-    /// See [`core::iter::Iterator::take`]
+    /// Merges two sorted fixed size iterators into one sorted iterator of length `N + M`.
+    ///
+    /// Both iterators are expected to be sorted in ascending order. If they are not, all
+    /// elements are still yielded exactly once but in an unspecified order. When two elements
+    /// are equal, the element from `self` comes first.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let res: [_; 5] = [1, 4, 6].into_iter_fixed().merge([2, 5]).collect();
+    ///
+    /// assert_eq!(res, [1, 2, 4, 5, 6]);
+    /// ```
     #[cfg(feature = "nightly_features")]
     #[inline]
-    pub fn take<const TAKE: usize>(
+    pub fn merge<IIF, const M: usize>(
         self,
-    ) -> IteratorFixed<impl Iterator<Item = I::Item>, { min(TAKE, N) }> {
-        IteratorFixed {
-            inner: self.inner.take(TAKE),
-        }
+        other: IIF,
+    ) -> IteratorFixed<impl Iterator<Item = I::Item>, { N + M }>
+    where
+        I::Item: Ord,
+        IIF: IntoIteratorFixed<M, Item = I::Item>,
+    {
+        self.merge_by(other, Ord::cmp)
     }
 
-    /// See [`core::iter::Iterator::zip`]
+    /// Merges two fixed size iterators which are sorted according to `compare` into one
+    /// sorted iterator of length `N + M`.
+    ///
+    /// See [`IteratorFixed::merge`]
+    #[cfg(feature = "nightly_features")]
     #[inline]
-    pub fn zip<IIF>(
+    pub fn merge_by<IIF, F, const M: usize>(
         self,
         other: IIF,
-    ) -> IteratorFixed<impl Iterator<Item = (I::Item, IIF::Item)>, N>
+        compare: F,
+    ) -> IteratorFixed<impl Iterator<Item = I::Item>, { N + M }>
     where
-        IIF: IntoIteratorFixed<N>,
+        IIF: IntoIteratorFixed<M, Item = I::Item>,
+        F: FnMut(&I::Item, &I::Item) -> Ordering,
     {
         IteratorFixed {
-            inner: self.inner.zip(other.into_iter_fixed().inner),
+            inner: Merge::new(self.inner, other.into_iter_fixed().inner, compare),
         }
     }
 
-    /*
-    pub fn unzip<A, B, FromA, FromB>(self) -> (FromA, FromB)
+    /// Merges two sorted fixed size iterators into one sorted iterator of length `L`, where
+    /// `L` has to be `N + M`.
+    ///
+    /// This is the stable counterpart of the nightly only `IteratorFixed::merge`, `L` is
+    /// usually inferred and checked at compile time.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let res: [_; 5] = [1, 4, 6].into_iter_fixed().merge_exact([2, 5]).collect();
+    ///
+    /// assert_eq!(res, [1, 2, 4, 5, 6]);
+    /// ```
+    #[inline]
+    pub fn merge_exact<const M: usize, const L: usize>(
+        self,
+        other: impl IntoIteratorFixed<M, Item = I::Item>,
+    ) -> IteratorFixed<impl Iterator<Item = I::Item>, L>
     where
-        I: Iterator<Item = (A, B)>,
-        FromA: FromIteratorFixed<A, N>,
-        FromB: FromIteratorFixed<B, N>,
+        I::Item: Ord,
     {
-        unimplemented!()
+        self.merge_by_exact(other, Ord::cmp)
     }
-    */
 
-    /// See [`core::iter::Iterator::rev`]
+    /// Merges two fixed size iterators which are sorted according to `compare` into one
+    /// sorted iterator of length `L`, where `L` has to be `N + M`.
+    ///
+    /// See [`IteratorFixed::merge_exact`]
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let res: [_; 4] = [6, 1]
+    ///     .into_iter_fixed()
+    ///     .merge_by_exact([5, 2], |a, b| b.cmp(a))
+    ///     .collect();
+    ///
+    /// assert_eq!(res, [6, 5, 2, 1]);
+    /// ```
     #[inline]
-    pub fn rev(self) -> IteratorFixed<impl Iterator<Item = I::Item>, N>
-    where
-        I: iter::DoubleEndedIterator,
-    {
+    pub fn merge_by_exact<const M: usize, const L: usize>(
+        self,
+        other: impl IntoIteratorFixed<M, Item = I::Item>,
+        compare: impl FnMut(&I::Item, &I::Item) -> Ordering,
+    ) -> IteratorFixed<impl Iterator<Item = I::Item>, L> {
+        const { const_assert(N + M == L, "L must be N + M") };
         IteratorFixed {
-            inner: self.inner.rev(),
+            inner: Merge::new(self.inner, other.into_iter_fixed().inner, compare),
         }
     }
 
+    /// Inserts `item` at position `INDEX`, shifting all elements after it to the right.
+    ///
+    /// That `INDEX <= N` is checked at compile time.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let homogeneous: [_; 4] = [1.0, 2.0, 3.0].into_iter_fixed().insert::<3>(1.0).collect();
+    ///
+    /// assert_eq!(homogeneous, [1.0, 2.0, 3.0, 1.0]);
+    /// ```
     #[cfg(feature = "nightly_features")]
     #[inline]
-    pub fn flatten<IIF, const M: usize>(
+    pub fn insert<const INDEX: usize>(
         self,
-    ) -> IteratorFixed<impl Iterator<Item = IIF::Item>, { M * N }>
-    where
-        I: Iterator<Item = IIF>,
-        IIF: IntoIteratorFixed<M>,
-    {
-        // The call to into_iter_fixed is needed because we cannot trust that
-        // let x: I::Item;
-        // x.into_iterator() == x.into_iter_fixed().into_iterator()
+        item: I::Item,
+    ) -> IteratorFixed<impl Iterator<Item = I::Item>, { N + 1 }> {
+        self.insert_exact::<INDEX, { N + 1 }>(item)
+    }
+
+    /// Inserts `item` at position `INDEX`, shifting all elements after it to the right. The
+    /// resulting length `L` has to be `N + 1`.
+    ///
+    /// This is the stable counterpart of the nightly only `IteratorFixed::insert`, `L` is
+    /// usually inferred. That `INDEX <= N` and `L == N + 1` is checked at compile time.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let res: [_; 4] = [1, 2, 3].into_iter_fixed().insert_exact::<1, 4>(7).collect();
+    ///
+    /// assert_eq!(res, [1, 7, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn insert_exact<const INDEX: usize, const L: usize>(
+        self,
+        item: I::Item,
+    ) -> IteratorFixed<impl Iterator<Item = I::Item>, L> {
+        const { const_assert(INDEX <= N, "INDEX must not be greater than N") };
+        const { const_assert(N + 1 == L, "L must be N + 1") };
         IteratorFixed {
-            inner: self.inner.flat_map(IntoIteratorFixed::into_iter_fixed),
+            inner: Insert::new(self.inner, INDEX, item),
         }
     }
 
+    /// Removes and returns the element at position `INDEX` along with an iterator over the
+    /// remaining elements.
+    ///
+    /// The elements before `INDEX` are buffered, the elements after it are still yielded
+    /// lazily. That `INDEX < N` is checked at compile time.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let (alpha, rgb) = [0.1, 0.2, 0.3, 1.0].into_iter_fixed().remove::<3>();
+    /// let rgb: [_; 3] = rgb.collect();
+    ///
+    /// assert_eq!(alpha, 1.0);
+    /// assert_eq!(rgb, [0.1, 0.2, 0.3]);
+    /// ```
     #[cfg(feature = "nightly_features")]
     #[inline]
-    pub fn flat_map<F, IIF, const M: usize>(
+    pub fn remove<const INDEX: usize>(
         self,
-        mut f: F,
-    ) -> IteratorFixed<impl Iterator<Item = IIF::Item>, { M * N }>
-    where
-        F: FnMut(I::Item) -> IIF,
-        IIF: IntoIteratorFixed<M>,
-    {
-        // The call to into_iter_fixed is needed because we cannot trust that
-        // let x: I::Item;
-        // x.into_iterator() == x.into_iter_fixed().into_iterator()
-        IteratorFixed {
-            inner: self.inner.flat_map(move |x| f(x).into_iter_fixed()),
-        }
+    ) -> (
+        I::Item,
+        IteratorFixed<impl Iterator<Item = I::Item>, { N - 1 }>,
+    ) {
+        self.remove_exact::<INDEX, { N - 1 }>()
     }
 
-    /// Transforms a fixed size iterator into a collection of compile time known size.
+    /// Removes and returns the element at position `INDEX` along with an iterator over the
+    /// remaining elements. The remaining length `L` has to be `N - 1`.
+    ///
+    /// This is the stable counterpart of the nightly only `IteratorFixed::remove`, `L` is
+    /// usually inferred. That `INDEX < N` and `L == N - 1` is checked at compile time.
     ///
     /// Basic usage:
     /// ```
     /// use iter_fixed::IntoIteratorFixed;
     ///
-    /// let two_four_six = [1, 2, 3].into_iter_fixed().map(|x| 2 * x);
+    /// let (removed, rest) = [1, 2, 3].into_iter_fixed().remove_exact::<1, 2>();
+    /// let rest: [_; 2] = rest.collect();
     ///
-    /// let a: [i32; 3] = two_four_six.collect();
-    /// assert_eq!(a, [2, 4, 6]);
+    /// assert_eq!(removed, 2);
+    /// assert_eq!(rest, [1, 3]);
     /// ```
     #[inline]
-    pub fn collect<U: FromIteratorFixed<I::Item, N>>(self) -> U {
-        U::from_iter_fixed(self)
+    pub fn remove_exact<const INDEX: usize, const L: usize>(
+        self,
+    ) -> (I::Item, IteratorFixed<impl Iterator<Item = I::Item>, L>) {
+        const { const_assert(INDEX < N, "INDEX must be less than N") };
+        const { const_assert(L + 1 == N, "L must be N - 1") };
+        let mut inner = self.inner;
+
+        // We know that inner yields N > INDEX elements due to it originating from an
+        // IteratorFixed of size N
+        let head: [I::Item; INDEX] = core::array::from_fn(|_| inner.next().unwrap());
+        let removed = inner.next().unwrap();
+        (
+            removed,
+            IteratorFixed {
+                inner: head.into_iter().chain(inner),
+            },
+        )
     }
-}
 
-impl<'a, I, T: 'a, const N: usize> IteratorFixed<I, N>
-where
-    I: Iterator<Item = &'a T>,
-{
-    /// See [`core::iter::Iterator::copied`]
+    /// Replaces the element at position `INDEX` with `item`, the old element is dropped.
+    ///
+    /// That `INDEX < N` is checked at compile time.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let res: [_; 3] = [1, 2, 3].into_iter_fixed().replace::<0>(7).collect();
+    ///
+    /// assert_eq!(res, [7, 2, 3]);
+    /// ```
     #[inline]
-    pub fn copied(self) -> IteratorFixed<impl Iterator<Item = T>, N>
-    where
-        T: Copy,
-    {
+    pub fn replace<const INDEX: usize>(
+        self,
+        item: I::Item,
+    ) -> IteratorFixed<impl Iterator<Item = I::Item>, N> {
+        const { const_assert(INDEX < N, "INDEX must be less than N") };
+        let mut item = Some(item);
+        self.enumerate().map(move |(i, x)| {
+            if i == INDEX {
+                // INDEX only occurs once so `item` is still there
+                item.take().unwrap()
+            } else {
+                x
+            }
+        })
+    }
+
+    /// Swaps the elements at positions `A` and `B`.
+    ///
+    /// That `A < N` and `B < N` is checked at compile time.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let res: [_; 4] = [1, 2, 3, 4].into_iter_fixed().swap::<0, 2>().collect();
+    ///
+    /// assert_eq!(res, [3, 2, 1, 4]);
+    /// ```
+    #[inline]
+    pub fn swap<const A: usize, const B: usize>(
+        self,
+    ) -> IteratorFixed<impl Iterator<Item = I::Item>, N> {
+        const { const_assert(A < N, "A must be less than N") };
+        const { const_assert(B < N, "B must be less than N") };
+        let mut array: [I::Item; N] = self.collect();
+        array.swap(A, B);
+        array.into_iter_fixed()
+    }
+
+    /// Splits the iterator into one iterator over the first `K` elements and one over the
+    /// remaining `N - K` elements.
+    ///
+    /// The first `K` elements are buffered, the remaining elements are still yielded lazily
+    /// from the source. This is also the case for iterators directly over an array, for those
+    /// [`IteratorFixed::split_array_at_exact`] splits without buffering or moving the elements.
+    /// That `K <= N` is checked at compile time.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let (xyz, w) = [1, 2, 3, 4].into_iter_fixed().split_at::<3>();
+    ///
+    /// assert_eq!(xyz.collect::<[_; 3]>(), [1, 2, 3]);
+    /// assert_eq!(w.collect::<[_; 1]>(), [4]);
+    /// ```
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    pub fn split_at<const K: usize>(
+        self,
+    ) -> (
+        IteratorFixed<impl Iterator<Item = I::Item>, K>,
+        IteratorFixed<impl Iterator<Item = I::Item>, { N - K }>,
+    ) {
+        self.split_at_exact::<K, { N - K }>()
+    }
+
+    /// Splits the iterator into one iterator over the first `K` elements and one over the
+    /// remaining `R` elements, where `R` has to be `N - K`.
+    ///
+    /// This is the stable counterpart of the nightly only `IteratorFixed::split_at`. That
+    /// `K + R == N` is checked at compile time. The first `K` elements are buffered, even for
+    /// iterators directly over an array. For those [`IteratorFixed::split_array_at_exact`]
+    /// avoids that.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let (head, tail) = [1, 2, 3, 4].into_iter_fixed().split_at_exact::<1, 3>();
+    ///
+    /// assert_eq!(head.collect::<[_; 1]>(), [1]);
+    /// assert_eq!(tail.collect::<[_; 3]>(), [2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn split_at_exact<const K: usize, const R: usize>(
+        self,
+    ) -> (
+        IteratorFixed<impl Iterator<Item = I::Item>, K>,
+        IteratorFixed<impl Iterator<Item = I::Item>, R>,
+    ) {
+        const { const_assert(K <= N, "K must not be greater than N") };
+        const { const_assert(K + R == N, "R must be N - K") };
+        let mut inner = self.inner;
+
+        // We know that inner yields N >= K elements due to it originating from an
+        // IteratorFixed of size N
+        let head: [I::Item; K] = core::array::from_fn(|_| inner.next().unwrap());
+        (head.into_iter_fixed(), IteratorFixed { inner })
+    }
+
+    /// Rotates the elements `K` positions to the left, so that the element at position
+    /// `K % N` comes first.
+    ///
+    /// `K % N` is computed at compile time. See [`slice::rotate_left`]
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let ring = [1, 2, 3, 4];
+    /// let neighbours: [_; 4] = ring.into_iter_fixed().rotate_left::<1>().zip(ring).collect();
+    ///
+    /// assert_eq!(neighbours, [(2, 1), (3, 2), (4, 3), (1, 4)]);
+    /// ```
+    #[inline]
+    pub fn rotate_left<const K: usize>(self) -> IteratorFixed<impl Iterator<Item = I::Item>, N> {
+        let mut array: [I::Item; N] = self.collect();
+        array.rotate_left(const { rem_or_zero(K, N) });
+        array.into_iter_fixed()
+    }
+
+    /// Rotates the elements `K` positions to the right, so that the first element ends up at
+    /// position `K % N`.
+    ///
+    /// `K % N` is computed at compile time. See [`slice::rotate_right`]
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let res: [_; 4] = [1, 2, 3, 4].into_iter_fixed().rotate_right::<5>().collect();
+    ///
+    /// assert_eq!(res, [4, 1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn rotate_right<const K: usize>(self) -> IteratorFixed<impl Iterator<Item = I::Item>, N> {
+        let mut array: [I::Item; N] = self.collect();
+        array.rotate_right(const { rem_or_zero(K, N) });
+        array.into_iter_fixed()
+    }
+
+    /// See [`core::iter::Iterator::enumerate`]
+    #[inline]
+    pub fn enumerate(self) -> IteratorFixed<iter::Enumerate<I>, N> {
         IteratorFixed {
-            inner: self.inner.copied(),
+            inner: self.inner.enumerate(),
         }
     }
 
-    /// See [`core::iter::Iterator::cloned`]
+    /// See [`core::iter::Iterator::take`]
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    pub fn take<const TAKE: usize>(
+        self,
+    ) -> IteratorFixed<impl Iterator<Item = I::Item>, { min(TAKE, N) }> {
+        IteratorFixed {
+            inner: self.inner.take(TAKE),
+        }
+    }
+
+    /// See [`core::iter::Iterator::zip`]
     #[inline]
-    pub fn cloned(self) -> IteratorFixed<impl Iterator<Item = T>, N>
+    pub fn zip<IIF>(self, other: IIF) -> IteratorFixed<iter::Zip<I, IIF::IntoIter>, N>
     where
-        T: Clone,
+        IIF: IntoIteratorFixed<N>,
     {
         IteratorFixed {
-            inner: self.inner.cloned(),
+            inner: self.inner.zip(other.into_iter_fixed().inner),
         }
     }
-}
-
-/// Convert the fixed size iterator into an ordinary [`core::iter::Iterator`]
-/// allowing it to be used with for loop syntax
-impl<T, I: Iterator<Item = T>, const N: usize> IntoIterator for IteratorFixed<I, N> {
-    type Item = T;
-    type IntoIter = I;
 
-    /// Convert the fixed size iterator into an ordinary [`core::iter::Iterator`]
+    /// Zips with an iterator of a possibly different length `M`, yielding `max(N, M)`
+    /// elements.
+    ///
+    /// Once the shorter iterator is exhausted, the elements of the longer one are yielded as
+    /// [`EitherOrBoth::Left`] or [`EitherOrBoth::Right`].
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::{EitherOrBoth, IntoIteratorFixed};
+    ///
+    /// let res: [_; 3] = [1, 2].into_iter_fixed().zip_longest(['a', 'b', 'c']).collect();
+    ///
+    /// assert_eq!(
+    ///     res,
+    ///     [
+    ///         EitherOrBoth::Both(1, 'a'),
+    ///         EitherOrBoth::Both(2, 'b'),
+    ///         EitherOrBoth::Right('c')
+    ///     ]
+    /// );
+    /// ```
+    #[cfg(feature = "nightly_features")]
     #[inline]
-    fn into_iter(self) -> I {
-        self.inner
+    pub fn zip_longest<IIF, const M: usize>(
+        self,
+        other: IIF,
+    ) -> IteratorFixed<impl Iterator<Item = EitherOrBoth<I::Item, IIF::Item>>, { max(N, M) }>
+    where
+        IIF: IntoIteratorFixed<M>,
+    {
+        self.zip_longest_exact::<IIF, M, { max(N, M) }>(other)
+    }
+
+    /// Zips with an iterator of a possibly different length `M`, yielding `L` elements where
+    /// `L` has to be `max(N, M)`.
+    ///
+    /// This is the stable counterpart of the nightly only `IteratorFixed::zip_longest`, `L` is
+    /// usually inferred and checked at compile time.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let res: [_; 3] = [1, 2, 3]
+    ///     .into_iter_fixed()
+    ///     .zip_longest_exact([10])
+    ///     .map(|x| x.or(0, 0))
+    ///     .collect();
+    ///
+    /// assert_eq!(res, [(1, 10), (2, 0), (3, 0)]);
+    /// ```
+    #[inline]
+    pub fn zip_longest_exact<IIF, const M: usize, const L: usize>(
+        self,
+        other: IIF,
+    ) -> IteratorFixed<impl Iterator<Item = EitherOrBoth<I::Item, IIF::Item>>, L>
+    where
+        IIF: IntoIteratorFixed<M>,
+    {
+        const { const_assert(max(N, M) == L, "L must be max(N, M)") };
+        IteratorFixed {
+            inner: ZipLongest::new(self.inner, other.into_iter_fixed().inner),
+        }
+    }
+
+    /// Zips with an iterator of a possibly different length `M`, yielding `min(N, M)`
+    /// elements.
+    ///
+    /// The remaining elements of the longer iterator are not yielded.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let res: [_; 2] = [1, 2].into_iter_fixed().zip_shortest(['a', 'b', 'c']).collect();
+    ///
+    /// assert_eq!(res, [(1, 'a'), (2, 'b')]);
+    /// ```
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    pub fn zip_shortest<IIF, const M: usize>(
+        self,
+        other: IIF,
+    ) -> IteratorFixed<impl Iterator<Item = (I::Item, IIF::Item)>, { min(N, M) }>
+    where
+        IIF: IntoIteratorFixed<M>,
+    {
+        self.zip_shortest_exact::<IIF, M, { min(N, M) }>(other)
+    }
+
+    /// Zips with an iterator of a possibly different length `M`, yielding `L` elements where
+    /// `L` has to be `min(N, M)`.
+    ///
+    /// This is the stable counterpart of the nightly only `IteratorFixed::zip_shortest`, `L` is
+    /// usually inferred and checked at compile time.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let res: [_; 1] = [1, 2, 3].into_iter_fixed().zip_shortest_exact([10]).collect();
+    ///
+    /// assert_eq!(res, [(1, 10)]);
+    /// ```
+    #[inline]
+    pub fn zip_shortest_exact<IIF, const M: usize, const L: usize>(
+        self,
+        other: IIF,
+    ) -> IteratorFixed<impl Iterator<Item = (I::Item, IIF::Item)>, L>
+    where
+        IIF: IntoIteratorFixed<M>,
+    {
+        const { const_assert(min(N, M) == L, "L must be min(N, M)") };
+        IteratorFixed {
+            inner: self.inner.zip(other.into_iter_fixed().inner),
+        }
+    }
+
+    /// Zips with an ordinary iterator of unknown length, using `fill` in place of the missing
+    /// elements if it yields fewer than `N` elements.
+    ///
+    /// Any elements beyond the first `N` are not consumed.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let streamed = vec![10, 20];
+    /// let res: [_; 3] = [1, 2, 3]
+    ///     .into_iter_fixed()
+    ///     .zip_padded(streamed, 0)
+    ///     .collect();
+    ///
+    /// assert_eq!(res, [(1, 10), (2, 20), (3, 0)]);
+    /// ```
+    #[inline]
+    pub fn zip_padded<II>(
+        self,
+        iter: II,
+        fill: II::Item,
+    ) -> IteratorFixed<impl Iterator<Item = (I::Item, II::Item)>, N>
+    where
+        II: IntoIterator,
+        II::Item: Clone,
+    {
+        IteratorFixed {
+            inner: self.inner.zip(iter.into_iter().chain(iter::repeat(fill))),
+        }
+    }
+
+    /// Zips with an ordinary iterator of unknown length, failing if it yields fewer than `N`
+    /// elements.
+    ///
+    /// The first `N` elements of `iter` are buffered up front so that its length can be
+    /// checked before any element is yielded. Any elements beyond the first `N` are not
+    /// consumed.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::{IntoIteratorFixed, LengthError};
+    ///
+    /// let res: [_; 2] = [1, 2]
+    ///     .into_iter_fixed()
+    ///     .zip_checked(vec![10, 20, 30])
+    ///     .unwrap()
+    ///     .collect();
+    /// assert_eq!(res, [(1, 10), (2, 20)]);
+    ///
+    /// let err = [1, 2].into_iter_fixed().zip_checked(vec![10]).err();
+    /// assert_eq!(err, Some(LengthError { expected: 2, actual: 1 }));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    pub fn zip_checked<II>(
+        self,
+        iter: II,
+    ) -> Result<IteratorFixed<impl Iterator<Item = (I::Item, II::Item)>, N>, LengthError>
+    where
+        II: IntoIterator,
+    {
+        let mut iter = iter.into_iter().fuse();
+        let buffer: [Option<II::Item>; N] = core::array::from_fn(|_| iter.next());
+
+        // `iter` is fused so all elements after the first `None` are `None` too
+        let actual = buffer.iter().take_while(|x| x.is_some()).count();
+        if actual < N {
+            return Err(LengthError {
+                expected: N,
+                actual,
+            });
+        }
+
+        // All elements of `buffer` have been checked to be `Some` above
+        Ok(self.zip(buffer).map(|(a, b)| (a, b.unwrap())))
+    }
+
+    /*
+    pub fn unzip<A, B, FromA, FromB>(self) -> (FromA, FromB)
+    where
+        I: Iterator<Item = (A, B)>,
+        FromA: FromIteratorFixed<A, N>,
+        FromB: FromIteratorFixed<B, N>,
+    {
+        unimplemented!()
+    }
+    */
+
+    /// Takes each element from `self` where `mask` is set and from `other` otherwise.
+    ///
+    /// Both sides are always consumed, the element that is not selected is dropped.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let res: [_; 4] = [1, 2, 3, 4]
+    ///     .into_iter_fixed()
+    ///     .select([true, false, false, true], [10, 20, 30, 40])
+    ///     .collect();
+    ///
+    /// assert_eq!(res, [1, 20, 30, 4]);
+    /// ```
+    #[inline]
+    pub fn select(
+        self,
+        mask: impl IntoIteratorFixed<N, Item = bool>,
+        other: impl IntoIteratorFixed<N, Item = I::Item>,
+    ) -> IteratorFixed<impl Iterator<Item = I::Item>, N> {
+        self.zip(mask)
+            .zip(other)
+            .map(|((a, m), b)| if m { a } else { b })
+    }
+
+    /// Combines the elements of `self` and `other` pairwise using `f`, which is also given the
+    /// index of the elements.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let res: [_; 4] = [1, 2, 3, 4]
+    ///     .into_iter_fixed()
+    ///     .blend_with([10, 20, 30, 40], |i, a, b| if i % 2 == 0 { a } else { a + b })
+    ///     .collect();
+    ///
+    /// assert_eq!(res, [1, 22, 3, 44]);
+    /// ```
+    #[inline]
+    pub fn blend_with<IIF, U, F>(
+        self,
+        other: IIF,
+        mut f: F,
+    ) -> IteratorFixed<impl Iterator<Item = U>, N>
+    where
+        IIF: IntoIteratorFixed<N>,
+        F: FnMut(usize, I::Item, IIF::Item) -> U,
+    {
+        self.zip(other)
+            .enumerate()
+            .map(move |(i, (a, b))| f(i, a, b))
+    }
+
+    /// Wraps the iterator in the left side of an [`Either`], so it can be used where another
+    /// pipeline of the same length is wrapped with [`right_fixed`](IteratorFixed::right_fixed)
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let reverse = true;
+    /// let a = [1, 2, 3];
+    /// let it = if reverse {
+    ///     a.into_iter_fixed().rev().left_fixed()
+    /// } else {
+    ///     a.into_iter_fixed().map(|x| 2 * x).right_fixed()
+    /// };
+    ///
+    /// let res: [_; 3] = it.collect();
+    /// assert_eq!(res, [3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn left_fixed<R>(self) -> IteratorFixed<Either<I, R>, N>
+    where
+        R: Iterator<Item = I::Item>,
+    {
+        IteratorFixed {
+            inner: Either::Left(self.inner),
+        }
+    }
+
+    /// Wraps the iterator in the right side of an [`Either`], so it can be used where another
+    /// pipeline of the same length is wrapped with [`left_fixed`](IteratorFixed::left_fixed)
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let reverse = false;
+    /// let a = [1, 2, 3];
+    /// let it = if reverse {
+    ///     a.into_iter_fixed().rev().left_fixed()
+    /// } else {
+    ///     a.into_iter_fixed().map(|x| 2 * x).right_fixed()
+    /// };
+    ///
+    /// let res: [_; 3] = it.collect();
+    /// assert_eq!(res, [2, 4, 6]);
+    /// ```
+    #[inline]
+    pub fn right_fixed<L>(self) -> IteratorFixed<Either<L, I>, N>
+    where
+        L: Iterator<Item = I::Item>,
+    {
+        IteratorFixed {
+            inner: Either::Right(self.inner),
+        }
+    }
+
+    /// Erases the type of the adapter chain while keeping the length `N` in the type
+    ///
+    /// This allows storing differently built pipelines of the same length together, at the
+    /// cost of a heap allocation and dynamic dispatch. The boxed iterator is no longer double
+    /// ended, so for example [`rev`](IteratorFixed::rev) has to be applied before boxing. Use
+    /// [`boxed_send`](IteratorFixed::boxed_send) if the result needs to be [`Send`].
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::{BoxedFixed, IntoIteratorFixed};
+    ///
+    /// let input = [1, 2, 3];
+    /// let chains: Vec<BoxedFixed<'_, i32, 3>> = vec![
+    ///     input.into_iter_fixed().boxed(),
+    ///     input.into_iter_fixed().rev().boxed(),
+    ///     input.into_iter_fixed().map(|x| x * x).boxed(),
+    /// ];
+    ///
+    /// let res: Vec<[i32; 3]> = chains.into_iter().map(|c| c.collect()).collect();
+    /// assert_eq!(res, [[1, 2, 3], [3, 2, 1], [1, 4, 9]]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn boxed<'a>(self) -> BoxedFixed<'a, I::Item, N>
+    where
+        I: 'a,
+    {
+        IteratorFixed {
+            inner: Box::new(self.inner),
+        }
+    }
+
+    /// Erases the type of the adapter chain like [`boxed`](IteratorFixed::boxed), but keeps
+    /// the result [`Send`]
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::{BoxedFixedSend, IntoIteratorFixed};
+    ///
+    /// let chain: BoxedFixedSend<'static, i32, 3> = [1, 2, 3].into_iter_fixed().rev().boxed_send();
+    ///
+    /// let res = std::thread::spawn(move || chain.collect::<[_; 3]>()).join().unwrap();
+    /// assert_eq!(res, [3, 2, 1]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn boxed_send<'a>(self) -> BoxedFixedSend<'a, I::Item, N>
+    where
+        I: Send + 'a,
+    {
+        IteratorFixed {
+            inner: Box::new(self.inner),
+        }
+    }
+
+    /// See [`core::iter::Iterator::rev`]
+    #[inline]
+    pub fn rev(self) -> IteratorFixed<iter::Rev<I>, N>
+    where
+        I: iter::DoubleEndedIterator,
+    {
+        IteratorFixed {
+            inner: self.inner.rev(),
+        }
+    }
+
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    pub fn flatten<IIF, const M: usize>(
+        self,
+    ) -> IteratorFixed<impl Iterator<Item = IIF::Item>, { M * N }>
+    where
+        I: Iterator<Item = IIF>,
+        IIF: IntoIteratorFixed<M>,
+    {
+        // The call to into_iter_fixed is needed because we cannot trust that
+        // let x: I::Item;
+        // x.into_iterator() == x.into_iter_fixed().into_iterator()
+        IteratorFixed {
+            inner: self.inner.flat_map(IntoIteratorFixed::into_iter_fixed),
+        }
+    }
+
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    pub fn flat_map<F, IIF, const M: usize>(
+        self,
+        mut f: F,
+    ) -> IteratorFixed<impl Iterator<Item = IIF::Item>, { M * N }>
+    where
+        F: FnMut(I::Item) -> IIF,
+        IIF: IntoIteratorFixed<M>,
+    {
+        // The call to into_iter_fixed is needed because we cannot trust that
+        // let x: I::Item;
+        // x.into_iterator() == x.into_iter_fixed().into_iterator()
+        IteratorFixed {
+            inner: self.inner.flat_map(move |x| f(x).into_iter_fixed()),
+        }
+    }
+
+    /// Sorts the elements of the iterator.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements). For `N <= 32` the elements
+    /// are sorted using a sorting network which is fixed at compile time, and each
+    /// compare-exchange of the network selects where the elements go instead of branching on
+    /// the comparison. Iterators with `N > 32` are collected into an array which is sorted
+    /// with [`slice::sort_unstable_by`] instead, so they are not sorted by a network and do
+    /// branch.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let samples = [7, 1, 9, 3, 4];
+    /// let median = samples.into_iter_fixed().sorted().into_iter().nth(2);
+    ///
+    /// assert_eq!(median, Some(4));
+    /// ```
+    #[inline]
+    pub fn sorted(self) -> IteratorFixed<impl Iterator<Item = I::Item>, N>
+    where
+        I::Item: Ord,
+    {
+        self.sorted_by(Ord::cmp)
+    }
+
+    /// Sorts the elements of the iterator with a comparator function.
+    ///
+    /// See [`IteratorFixed::sorted`] and [`slice::sort_unstable_by`]
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let descending: [_; 4] = [2, 4, 1, 3]
+    ///     .into_iter_fixed()
+    ///     .sorted_by(|a, b| b.cmp(a))
+    ///     .collect();
+    ///
+    /// assert_eq!(descending, [4, 3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn sorted_by<F>(self, compare: F) -> IteratorFixed<impl Iterator<Item = I::Item>, N>
+    where
+        F: FnMut(&I::Item, &I::Item) -> Ordering,
+    {
+        let mut array: [I::Item; N] = self.collect();
+        sort::sort_by(&mut array, compare);
+        array.into_iter_fixed()
+    }
+
+    /// Sorts the elements of the iterator with a key extraction function.
+    ///
+    /// See [`IteratorFixed::sorted`] and [`slice::sort_unstable_by_key`]
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let by_abs: [_; 4] = [-5i32, 4, 1, -3]
+    ///     .into_iter_fixed()
+    ///     .sorted_by_key(|x| x.abs())
+    ///     .collect();
+    ///
+    /// assert_eq!(by_abs, [1, -3, 4, -5]);
+    /// ```
+    #[inline]
+    pub fn sorted_by_key<K, F>(self, mut f: F) -> IteratorFixed<impl Iterator<Item = I::Item>, N>
+    where
+        K: Ord,
+        F: FnMut(&I::Item) -> K,
+    {
+        self.sorted_by(move |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Returns the indices that would sort the elements of the iterator.
+    ///
+    /// The `i`th element of the result is the index of the `i`th smallest element. Equal
+    /// elements keep their relative order. This is the inverse permutation of
+    /// [`IteratorFixed::rank`].
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let keys = [30, 10, 20];
+    /// let values = ['c', 'a', 'b'];
+    ///
+    /// let order: [usize; 3] = (&keys).into_iter_fixed().argsort().collect();
+    /// assert_eq!(order, [1, 2, 0]);
+    ///
+    /// let reordered: [_; 3] = order.into_iter_fixed().map(|i| values[i]).collect();
+    /// assert_eq!(reordered, ['a', 'b', 'c']);
+    /// ```
+    #[inline]
+    pub fn argsort(self) -> IteratorFixed<impl Iterator<Item = usize>, N>
+    where
+        I::Item: Ord,
+    {
+        let array: [I::Item; N] = self.collect();
+        sort::argsort_by(&array, Ord::cmp).into_iter_fixed()
+    }
+
+    /// Returns the indices that would sort the elements of the iterator by a key extraction
+    /// function.
+    ///
+    /// See [`IteratorFixed::argsort`]
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let order: [usize; 4] = [-5i32, 4, 1, -3]
+    ///     .into_iter_fixed()
+    ///     .argsort_by_key(|x| x.abs())
+    ///     .collect();
+    ///
+    /// assert_eq!(order, [2, 3, 1, 0]);
+    /// ```
+    #[inline]
+    pub fn argsort_by_key<K, F>(self, mut f: F) -> IteratorFixed<impl Iterator<Item = usize>, N>
+    where
+        K: Ord,
+        F: FnMut(&I::Item) -> K,
+    {
+        let array: [I::Item; N] = self.collect();
+        sort::argsort_by(&array, |a, b| f(a).cmp(&f(b))).into_iter_fixed()
+    }
+
+    /// Returns the rank of each element of the iterator, that is the position the element
+    /// would have if the iterator was sorted.
+    ///
+    /// Equal elements are ranked in order of appearance. This is the inverse permutation of
+    /// [`IteratorFixed::argsort`].
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let ranks: [usize; 4] = [30, 10, 40, 20].into_iter_fixed().rank().collect();
+    ///
+    /// assert_eq!(ranks, [2, 0, 3, 1]);
+    /// ```
+    #[inline]
+    pub fn rank(self) -> IteratorFixed<impl Iterator<Item = usize>, N>
+    where
+        I::Item: Ord,
+    {
+        let order: [usize; N] = self.argsort().collect();
+        let mut ranks = [0; N];
+        for (rank, i) in order.into_iter().enumerate() {
+            ranks[i] = rank;
+        }
+        ranks.into_iter_fixed()
+    }
+
+    /// Returns the `K` largest elements of the iterator in descending order.
+    ///
+    /// This keeps a heap of `K` elements instead of sorting all `N` elements. That `K <= N`
+    /// is checked at compile time.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let best: [_; 2] = [3, 9, 1, 7, 4].into_iter_fixed().top_k::<2>().collect();
+    ///
+    /// assert_eq!(best, [9, 7]);
+    /// ```
+    #[inline]
+    pub fn top_k<const K: usize>(self) -> IteratorFixed<impl Iterator<Item = I::Item>, K>
+    where
+        I::Item: Ord,
+    {
+        const { const_assert(K <= N, "K must not be greater than N") };
+        sort::select_greatest_by::<_, _, K>(self.inner, Ord::cmp).into_iter_fixed()
+    }
+
+    /// Returns the `K` elements of the iterator with the largest keys in descending order of
+    /// their keys.
+    ///
+    /// See [`IteratorFixed::top_k`]
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let candidates = [("a", 0.5), ("b", 0.9), ("c", 0.1), ("d", 0.7)];
+    /// let best: [_; 2] = candidates
+    ///     .into_iter_fixed()
+    ///     .top_k_by_key::<2>(|&(_, score)| (score * 100.0) as u32)
+    ///     .map(|(name, _)| name)
+    ///     .collect();
+    ///
+    /// assert_eq!(best, ["b", "d"]);
+    /// ```
+    #[inline]
+    pub fn top_k_by_key<const K: usize>(
+        self,
+        mut f: impl KeyFn<I::Item>,
+    ) -> IteratorFixed<impl Iterator<Item = I::Item>, K> {
+        const { const_assert(K <= N, "K must not be greater than N") };
+        sort::select_greatest_by::<_, _, K>(self.inner, |a, b| f(a).cmp(&f(b))).into_iter_fixed()
+    }
+
+    /// Returns the `K` smallest elements of the iterator in ascending order.
+    ///
+    /// See [`IteratorFixed::top_k`]
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let worst: [_; 2] = [3, 9, 1, 7, 4].into_iter_fixed().bottom_k::<2>().collect();
+    ///
+    /// assert_eq!(worst, [1, 3]);
+    /// ```
+    #[inline]
+    pub fn bottom_k<const K: usize>(self) -> IteratorFixed<impl Iterator<Item = I::Item>, K>
+    where
+        I::Item: Ord,
+    {
+        const { const_assert(K <= N, "K must not be greater than N") };
+        sort::select_greatest_by::<_, _, K>(self.inner, |a, b| b.cmp(a)).into_iter_fixed()
+    }
+
+    /// Returns the `K` elements of the iterator with the smallest keys in ascending order of
+    /// their keys.
+    ///
+    /// See [`IteratorFixed::top_k`]
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let closest: [_; 2] = [-5i32, 4, 1, -3]
+    ///     .into_iter_fixed()
+    ///     .bottom_k_by_key::<2>(|x| x.abs())
+    ///     .collect();
+    ///
+    /// assert_eq!(closest, [1, -3]);
+    /// ```
+    #[inline]
+    pub fn bottom_k_by_key<const K: usize>(
+        self,
+        mut f: impl KeyFn<I::Item>,
+    ) -> IteratorFixed<impl Iterator<Item = I::Item>, K> {
+        const { const_assert(K <= N, "K must not be greater than N") };
+        sort::select_greatest_by::<_, _, K>(self.inner, |a, b| f(b).cmp(&f(a))).into_iter_fixed()
+    }
+
+    /// Creates an iterator of length `M` yielding the element at each of the given `indices`.
+    ///
+    /// Elements are cloned, so gathering from a borrowed array yields references. The
+    /// indices are all checked once before any element is yielded.
+    ///
+    /// # Panics
+    /// Panics if any index is not less than `N`.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let samples = [10, 11, 12, 13, 14, 15, 16, 17];
+    /// let bit_reversed = [0, 4, 2, 6, 1, 5, 3, 7];
+    ///
+    /// let res: [&i32; 8] = (&samples).into_iter_fixed().gather(bit_reversed).collect();
+    /// assert_eq!(res, [&10, &14, &12, &16, &11, &15, &13, &17]);
+    ///
+    /// let res: [_; 3] = samples.into_iter_fixed().gather([7, 7, 0]).collect();
+    /// assert_eq!(res, [17, 17, 10]);
+    /// ```
+    #[inline]
+    pub fn gather<const M: usize>(
+        self,
+        indices: impl IntoIteratorFixed<M, Item = usize>,
+    ) -> IteratorFixed<impl Iterator<Item = I::Item>, M>
+    where
+        I::Item: Clone,
+    {
+        let array: [I::Item; N] = self.collect();
+        let indices: [usize; M] = indices.into_iter_fixed().collect();
+        assert!(indices.iter().all(|&i| i < N), "gather index out of bounds");
+
+        indices.into_iter_fixed().map(move |i| {
+            // Safety: all indices have been checked to be less than N above
+            unsafe { array.get_unchecked(i) }.clone()
+        })
+    }
+
+    /// Reorders the elements of the iterator so that the `i`th element yielded is the element
+    /// at index `permutation[i]`.
+    ///
+    /// Elements are moved, not cloned. The permutation is validated once before any element
+    /// is moved.
+    ///
+    /// # Panics
+    /// Panics if `permutation` is not a permutation of `0..N`, that is if any index is out of
+    /// bounds or occurs more than once.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let channels = [String::from("r"), String::from("g"), String::from("b")];
+    ///
+    /// let bgr: [_; 3] = channels.into_iter_fixed().permute([2, 1, 0]).collect();
+    /// assert_eq!(bgr, ["b", "g", "r"]);
+    /// ```
+    #[inline]
+    pub fn permute(
+        self,
+        permutation: impl IntoIteratorFixed<N, Item = usize>,
+    ) -> IteratorFixed<impl Iterator<Item = I::Item>, N> {
+        let array: [I::Item; N] = self.collect();
+        let permutation: [usize; N] = permutation.into_iter_fixed().collect();
+
+        let mut seen = [false; N];
+        for &i in &permutation {
+            assert!(i < N && !seen[i], "invalid permutation");
+            seen[i] = true;
+        }
+
+        let array = ManuallyDrop::new(array);
+        let permuted: [I::Item; N] = core::array::from_fn(|i| {
+            // Safety: `permutation` contains every index in 0..N exactly once so every
+            // element of `array` is moved out exactly once, `array` is never dropped
+            unsafe { ptr::read(array.get_unchecked(*permutation.get_unchecked(i))) }
+        });
+        permuted.into_iter_fixed()
+    }
+
+    /// Writes each element of the iterator into `dest` at the corresponding index of
+    /// `indices`.
+    ///
+    /// The previous values in `dest` are dropped. If an index occurs more than once, the last
+    /// element written to it is kept. The indices are all checked once before any element is
+    /// written.
+    ///
+    /// # Panics
+    /// Panics if any index is not less than `M`.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let mut dest = [0; 5];
+    /// [1, 2, 3].into_iter_fixed().scatter_into(&mut dest, [4, 0, 2]);
+    ///
+    /// assert_eq!(dest, [2, 0, 3, 0, 1]);
+    /// ```
+    #[inline]
+    pub fn scatter_into<const M: usize>(
+        self,
+        dest: &mut [I::Item; M],
+        indices: impl IntoIteratorFixed<N, Item = usize>,
+    ) {
+        let indices: [usize; N] = indices.into_iter_fixed().collect();
+        assert!(
+            indices.iter().all(|&i| i < M),
+            "scatter index out of bounds"
+        );
+
+        for (x, i) in self.inner.zip(indices) {
+            // Safety: all indices have been checked to be less than M above
+            unsafe { *dest.get_unchecked_mut(i) = x };
+        }
+    }
+
+    /// Counts the number of elements in each of `K` buckets, where `bucket` returns the bucket
+    /// index of an element.
+    ///
+    /// Elements with a bucket index that is not less than `K` are counted in
+    /// [`Buckets::overflow`].
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let samples = [0.1, 0.7, 0.4, 0.9, 1.5, 0.3];
+    /// let histogram = samples
+    ///     .into_iter_fixed()
+    ///     .histogram::<4>(|&x| (x * 4.0) as usize);
+    ///
+    /// assert_eq!(histogram.buckets, [1, 2, 1, 1]);
+    /// assert_eq!(histogram.overflow, 1);
+    /// ```
+    #[inline]
+    pub fn histogram<const K: usize>(
+        self,
+        bucket: impl FnMut(&I::Item) -> usize,
+    ) -> Buckets<usize, K> {
+        self.group_into::<K, usize>(bucket, |count, _| *count += 1)
+    }
+
+    /// Folds every element into one of `K` accumulators, where `bucket` returns the bucket
+    /// index of an element and `fold` adds the element to the accumulator of that bucket.
+    ///
+    /// Every accumulator starts out as `C::default()`. Elements with a bucket index that is
+    /// not less than `K` are dropped and counted in [`Buckets::overflow`].
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let sums = [1, 2, 3, 4, 5, 6, 7]
+    ///     .into_iter_fixed()
+    ///     .group_into::<3, u32>(|&x| x as usize % 3, |sum, x| *sum += x);
+    ///
+    /// assert_eq!(sums.buckets, [3 + 6, 1 + 4 + 7, 2 + 5]);
+    /// assert_eq!(sums.overflow, 0);
+    /// ```
+    #[inline]
+    pub fn group_into<const K: usize, C: Default>(
+        self,
+        mut bucket: impl FnMut(&I::Item) -> usize,
+        mut fold: impl FnMut(&mut C, I::Item),
+    ) -> Buckets<C, K> {
+        let mut buckets: [C; K] = core::array::from_fn(|_| C::default());
+        let mut overflow = 0;
+        for x in self.inner {
+            match buckets.get_mut(bucket(&x)) {
+                Some(acc) => fold(acc, x),
+                None => overflow += 1,
+            }
+        }
+
+        Buckets { buckets, overflow }
+    }
+
+    /// Transforms a fixed size iterator into a collection of compile time known size.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let two_four_six = [1, 2, 3].into_iter_fixed().map(|x| 2 * x);
+    ///
+    /// let a: [i32; 3] = two_four_six.collect();
+    /// assert_eq!(a, [2, 4, 6]);
+    /// ```
+    #[inline]
+    pub fn collect<U: FromIteratorFixed<I::Item, N>>(self) -> U {
+        U::from_iter_fixed(self)
+    }
+
+    /// Replaces the contents of existing storage of compile time known size with the elements
+    /// of the iterator, returning a reference to it.
+    ///
+    /// The old elements of `dest` are dropped. This avoids building a temporary collection
+    /// that is then moved into place.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// struct Filter {
+    ///     state: [f32; 4],
+    /// }
+    ///
+    /// let mut filter = Filter { state: [0.0; 4] };
+    /// [1.0, 2.0, 3.0, 4.0]
+    ///     .into_iter_fixed()
+    ///     .map(|x| x * 0.5)
+    ///     .collect_into(&mut filter.state);
+    ///
+    /// assert_eq!(filter.state, [0.5, 1.0, 1.5, 2.0]);
+    /// ```
+    #[inline]
+    pub fn collect_into<U>(self, dest: &mut U) -> &mut U
+    where
+        U: FillFromIteratorFixed<I::Item, N>,
+    {
+        dest.fill_from_iter_fixed(self);
+        dest
+    }
+
+    /// Initializes `dest` with the elements of the iterator in place, returning a reference to
+    /// the now initialized array.
+    ///
+    /// Any previous contents of `dest` are overwritten without being dropped. If the iterator
+    /// panics, the elements written so far are dropped and `dest` is left uninitialized.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let mut storage = [const { MaybeUninit::uninit() }; 3];
+    /// let a: &mut [String; 3] = [1, 2, 3]
+    ///     .into_iter_fixed()
+    ///     .map(|x| x.to_string())
+    ///     .write_uninit(&mut storage);
+    ///
+    /// assert_eq!(a, &["1", "2", "3"]);
+    /// # unsafe { core::ptr::drop_in_place(a) };
+    /// ```
+    #[inline]
+    pub fn write_uninit(self, dest: &mut [MaybeUninit<I::Item>; N]) -> &mut [I::Item; N] {
+        uninit::write_slots(dest, self);
+
+        // Safety: All N elements of `dest` have been initialized and MaybeUninit<T> has the
+        // same layout as T
+        unsafe { &mut *(dest as *mut [MaybeUninit<I::Item>; N]).cast::<[I::Item; N]>() }
+    }
+}
+
+impl<T, const N: usize> IteratorFixed<array::IntoIter<T, N>, N> {
+    /// Splits an iterator over an array into iterators over its first `K` and its remaining
+    /// `R` elements and passes both to `f`, where `R` has to be `N - K`.
+    ///
+    /// Unlike [`IteratorFixed::split_at_exact`] nothing is buffered or copied, both iterators
+    /// move the elements directly out of the storage of the array. They borrow that storage,
+    /// which is why they are only available inside `f`. Elements that are not yielded are
+    /// dropped together with their iterator. That `K + R == N` is checked at compile time.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let (head, tail) = [1, 2, 3, 4]
+    ///     .into_iter_fixed()
+    ///     .split_array_at_exact::<1, 3, _>(|head, tail| {
+    ///         let head: [_; 1] = head.collect();
+    ///         let tail: [_; 3] = tail.rev().collect();
+    ///         (head, tail)
+    ///     });
+    ///
+    /// assert_eq!(head, [1]);
+    /// assert_eq!(tail, [4, 3, 2]);
+    /// ```
+    #[inline]
+    pub fn split_array_at_exact<const K: usize, const R: usize, O>(
+        self,
+        f: impl for<'a> FnOnce(
+            IteratorFixed<ArrayDrain<'a, T>, K>,
+            IteratorFixed<ArrayDrain<'a, T>, R>,
+        ) -> O,
+    ) -> O {
+        const { const_assert(K <= N, "K must not be greater than N") };
+        const { const_assert(K + R == N, "R must be N - K") };
+
+        // The elements are owned by the two ArrayDrain from here on, so `inner` must not drop
+        // them
+        let mut inner = ManuallyDrop::new(self.inner);
+        // An IteratorFixed<_, N> yields N elements so none have been taken out yet
+        debug_assert_eq!(inner.len(), N);
+        let slice = inner.as_mut_slice();
+        // Safety: MaybeUninit<T> has the same layout as T
+        let slots = unsafe { &mut *(slice as *mut [T] as *mut [MaybeUninit<T>]) };
+        let (head, tail) = slots.split_at_mut(K);
+
+        // Safety: All N elements are initialized and only moved out or dropped by the
+        // ArrayDrain owning them. K + R == N as checked above, so the two parts yield K and R
+        // elements
+        let (head, tail) = unsafe {
+            (
+                IteratorFixed::from_iter(ArrayDrain::new(head)),
+                IteratorFixed::from_iter(ArrayDrain::new(tail)),
+            )
+        };
+        f(head, tail)
+    }
+
+    /// Maps every element of an array with `f`, writing the results back into the storage of
+    /// the array.
+    ///
+    /// Every result is written over the element it was computed from, so while mapping only
+    /// one array is held, where `map(f).collect()` holds both the source and the result array.
+    /// For large arrays like `[f32; 1024]` this halves the stack used by the mapping. The
+    /// mapped array is moved out once all elements have been mapped, like any other returned
+    /// array. `U` has to have the same size and alignment as `T`, which is checked at compile
+    /// time. If `f` panics, both the already mapped and the remaining elements are dropped.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let samples = [1.0f32, -2.0, 3.0, -4.0];
+    /// let bits: [u32; 4] = samples.into_iter_fixed().map_in_place(|x| x.abs().to_bits());
+    ///
+    /// assert_eq!(bits, [1.0f32, 2.0, 3.0, 4.0].map(f32::to_bits));
+    /// ```
+    ///
+    /// Types of a different size are rejected at compile time:
+    /// ```compile_fail
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: [u64; 2] = [1u32, 2].into_iter_fixed().map_in_place(u64::from);
+    /// ```
+    #[inline]
+    pub fn map_in_place<U, F: FnMut(T) -> U>(self, f: F) -> [U; N] {
+        const { const_assert(same_size::<T, U>(), "T and U have to have the same size") };
+        const {
+            const_assert(
+                same_align::<T, U>(),
+                "T and U have to have the same alignment",
+            )
+        };
+
+        // The elements are mapped or dropped by `map_elements`, so `inner` must not drop them
+        let mut inner = ManuallyDrop::new(self.inner);
+        // An IteratorFixed<_, N> yields N elements so none have been taken out yet
+        debug_assert_eq!(inner.len(), N);
+        let ptr = inner.as_mut_slice().as_mut_ptr();
+
+        // Safety: All N elements are initialized and `inner` never reads or drops them again.
+        // T and U have the same layout as checked above, so afterwards `ptr` points to N
+        // initialized elements of type U
+        unsafe {
+            uninit::map_elements::<T, U, F, N>(ptr, f);
+            ptr.cast::<[U; N]>().read()
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> IteratorFixed<BoxIntoIter<T, N>, N> {
+    /// Maps every element of a boxed array with `f`, writing the results back into the same
+    /// allocation.
+    ///
+    /// No second array is created, neither on the heap nor on the stack, so this works for
+    /// buffers that would not fit on the stack. `U` has to have the same size and alignment as
+    /// `T`, which is checked at compile time. If `f` panics, both the already mapped and the
+    /// remaining elements are dropped and the allocation is freed.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let samples = Box::new([1.0f32, -2.0, 3.0, -4.0]);
+    /// let bits: Box<[u32; 4]> = samples.into_iter_fixed().map_in_place(|x| x.abs().to_bits());
+    ///
+    /// assert_eq!(*bits, [1.0f32, 2.0, 3.0, 4.0].map(f32::to_bits));
+    /// ```
+    ///
+    /// Types of a different size are rejected at compile time:
+    /// ```compile_fail
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let a: Box<[u64; 2]> = Box::new([1u32, 2]).into_iter_fixed().map_in_place(u64::from);
+    /// ```
+    #[inline]
+    pub fn map_in_place<U, F: FnMut(T) -> U>(self, f: F) -> Box<[U; N]> {
+        const { const_assert(same_size::<T, U>(), "T and U have to have the same size") };
+        const {
+            const_assert(
+                same_align::<T, U>(),
+                "T and U have to have the same alignment",
+            )
+        };
+
+        let inner = ManuallyDrop::new(self.inner);
+        // An IteratorFixed<_, N> yields N elements so none have been taken out yet
+        debug_assert_eq!(inner.alive, 0..N);
+        // Safety: `inner` is never used or dropped again, so the box is only owned once
+        let buf = unsafe { ptr::read(&inner.buf) };
+
+        // Safety: All N elements are initialized as stated above, and T and U have the same
+        // layout as checked above
+        unsafe { uninit::map_in_place(buf, f) }
+    }
+}
+
+impl<I, const N: usize> IteratorFixed<I, N>
+where
+    I: Iterator<Item = bool>,
+{
+    /// Returns the number of `true` elements, like [`u32::count_ones`] for a mask
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let mask = [true, false, true, true];
+    ///
+    /// assert_eq!(mask.into_iter_fixed().count_ones(), 3);
+    /// ```
+    #[inline]
+    pub fn count_ones(self) -> usize {
+        self.inner.filter(|&b| b).count()
+    }
+
+    /// Returns the number of `false` elements, like [`u32::count_zeros`] for a mask
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let mask = [true, false, true, true];
+    ///
+    /// assert_eq!(mask.into_iter_fixed().count_zeros(), 1);
+    /// ```
+    #[inline]
+    pub fn count_zeros(self) -> usize {
+        N - self.count_ones()
+    }
+}
+
+impl<'a, I, T: 'a, const N: usize> IteratorFixed<I, N>
+where
+    I: Iterator<Item = &'a T>,
+{
+    /// See [`core::iter::Iterator::copied`]
+    #[inline]
+    pub fn copied(self) -> IteratorFixed<iter::Copied<I>, N>
+    where
+        T: Copy,
+    {
+        IteratorFixed {
+            inner: self.inner.copied(),
+        }
+    }
+
+    /// See [`core::iter::Iterator::cloned`]
+    #[inline]
+    pub fn cloned(self) -> IteratorFixed<iter::Cloned<I>, N>
+    where
+        T: Clone,
+    {
+        IteratorFixed {
+            inner: self.inner.cloned(),
+        }
+    }
+}
+
+/// Convert the fixed size iterator into an ordinary [`core::iter::Iterator`]
+/// allowing it to be used with for loop syntax
+impl<T, I: Iterator<Item = T>, const N: usize> IntoIterator for IteratorFixed<I, N> {
+    type Item = T;
+    type IntoIter = Exact<I, N>;
+
+    /// Convert the fixed size iterator into an ordinary [`core::iter::Iterator`]
+    ///
+    /// The returned iterator still knows its length, so it implements [`ExactSizeIterator`]
+    /// and for example collecting it into a `Vec` only allocates once.
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::IntoIteratorFixed;
+    ///
+    /// let v: Vec<_> = [1, 2, 3].into_iter_fixed().map(|x| 2 * x).into_iter().collect();
+    ///
+    /// assert_eq!(v, [2, 4, 6]);
+    /// ```
+    #[inline]
+    fn into_iter(self) -> Exact<I, N> {
+        Exact {
+            inner: self.inner,
+            remaining: N,
+        }
     }
 }
@@ -0,0 +1,50 @@
+use core::{cmp::Ordering, iter::Peekable};
+
+/// Iterator merging two sorted iterators, see [`crate::IteratorFixed::merge_by`]
+///
+/// Every element of both iterators is yielded exactly once no matter the order of their
+/// elements, unsorted input only affects the order of the output.
+pub(crate) struct Merge<A: Iterator, B: Iterator, F> {
+    a: Peekable<A>,
+    b: Peekable<B>,
+    compare: F,
+}
+
+impl<A: Iterator, B: Iterator, F> Merge<A, B, F> {
+    pub(crate) fn new(a: A, b: B, compare: F) -> Self {
+        Self {
+            a: a.peekable(),
+            b: b.peekable(),
+            compare,
+        }
+    }
+}
+
+impl<A, B, F> Iterator for Merge<A, B, F>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+    F: FnMut(&A::Item, &A::Item) -> Ordering,
+{
+    type Item = A::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.a.peek(), self.b.peek()) {
+            (Some(a), Some(b)) if (self.compare)(a, b) == Ordering::Greater => self.b.next(),
+            (Some(_), _) => self.a.next(),
+            (None, _) => self.b.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (a_lower.saturating_add(b_lower), upper)
+    }
+}
@@ -0,0 +1,86 @@
+use core::mem::MaybeUninit;
+#[cfg(feature = "nightly_features")]
+use core::{array, iter::TrustedRandomAccessNoCoerce, mem};
+
+#[cfg(feature = "nightly_features")]
+use crate::uninit::Guard;
+use crate::uninit::{collect_in_order, write_in_order};
+
+/// Collects the elements of the inner iterator of an [`IteratorFixed`](crate::IteratorFixed)
+///
+/// Every iterator can be collected by calling `next()` for each element. With
+/// `nightly_features` this is specialized for iterators where every stage can produce its
+/// elements by index, like `zip`, `map` and `enumerate` over arrays of `Copy` elements,
+/// references to arrays and [`from_fn`](crate::from_fn). Those are collected into arrays with
+/// `core::array::from_fn`, which compiles to the same index based loop as hand written array
+/// code.
+pub(crate) trait SpecCollect: Iterator + Sized {
+    /// Collects the elements into an array
+    ///
+    /// # Safety
+    /// `self` has to yield exactly `N` elements.
+    unsafe fn spec_collect_array<const N: usize>(self) -> [Self::Item; N];
+
+    /// Moves the elements into `slots`
+    ///
+    /// After this returns, all slots are initialized. If `self` panics the elements written
+    /// so far are dropped and `slots` is left uninitialized.
+    ///
+    /// # Safety
+    /// `self` has to yield exactly `slots.len()` elements.
+    unsafe fn spec_write_slots(self, slots: &mut [MaybeUninit<Self::Item>]);
+}
+
+/// Implements `SpecCollect` for every iterator by calling `next()`
+///
+/// With `nightly_features` the methods are passed `default` so they can be specialized. The
+/// keyword is only produced by the macro, since even code that is configured out must not
+/// contain it on a nightly compiler without the feature.
+macro_rules! impl_spec_collect_in_order {
+    ($($default:ident)?) => {
+        impl<I: Iterator> SpecCollect for I {
+            #[inline]
+            $($default)? unsafe fn spec_collect_array<const N: usize>(self) -> [I::Item; N] {
+                // Safety: Forwarded from the caller
+                unsafe { collect_in_order(self) }
+            }
+
+            #[inline]
+            $($default)? unsafe fn spec_write_slots(self, slots: &mut [MaybeUninit<I::Item>]) {
+                // Safety: Forwarded from the caller
+                unsafe { write_in_order(self, slots) }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "nightly_features"))]
+impl_spec_collect_in_order!();
+#[cfg(feature = "nightly_features")]
+impl_spec_collect_in_order!(default);
+
+#[cfg(feature = "nightly_features")]
+impl<I: Iterator + TrustedRandomAccessNoCoerce> SpecCollect for I {
+    #[inline]
+    unsafe fn spec_collect_array<const N: usize>(mut self) -> [I::Item; N] {
+        // Safety: The caller guarantees that `self` has N elements, and array::from_fn passes
+        // every index below N exactly once. Only `self` is dropped afterwards
+        array::from_fn(|i| unsafe { self.__iterator_get_unchecked(i) })
+    }
+
+    #[inline]
+    unsafe fn spec_write_slots(mut self, slots: &mut [MaybeUninit<I::Item>]) {
+        let mut guard = Guard {
+            slots,
+            initialized: 0,
+        };
+
+        for (i, slot) in guard.slots.iter_mut().enumerate() {
+            // Safety: The caller guarantees that `self` has `slots.len()` elements, and every
+            // index is passed exactly once. Only `self` is dropped afterwards
+            slot.write(unsafe { self.__iterator_get_unchecked(i) });
+            guard.initialized += 1;
+        }
+        mem::forget(guard);
+    }
+}
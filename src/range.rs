@@ -0,0 +1,331 @@
+use core::iter::FusedIterator;
+
+use crate::helpers::const_assert;
+use crate::IteratorFixed;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Primitive integer types which can be used with [`range`], [`range_step`] and their
+/// `const` counterparts
+///
+/// This trait is sealed and can not be implemented outside of this crate.
+pub trait RangeInt: Copy + sealed::Sealed {
+    #[doc(hidden)]
+    const ONE: Self;
+
+    /// Difference between the largest and smallest value of the type
+    #[doc(hidden)]
+    const MAX_OFFSET: u128;
+
+    /// Smallest value of the type
+    #[doc(hidden)]
+    const LOWER: i128;
+
+    /// Largest value of the type
+    #[doc(hidden)]
+    const UPPER: u128;
+
+    /// `self + step * n` or `None` on overflow
+    #[doc(hidden)]
+    fn checked_nth(self, step: Self, n: usize) -> Option<Self>;
+
+    /// `self + step * n` with wrapping arithmetic
+    #[doc(hidden)]
+    fn wrapping_nth(self, step: Self, n: usize) -> Self;
+
+    /// `value as Self`
+    #[doc(hidden)]
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! impl_range_int {
+    ($($t:ty)*) => {$(
+        impl sealed::Sealed for $t {}
+
+        impl RangeInt for $t {
+            const ONE: Self = 1;
+            const MAX_OFFSET: u128 = <$t>::MAX.abs_diff(<$t>::MIN) as u128;
+            const LOWER: i128 = <$t>::MIN as i128;
+            const UPPER: u128 = <$t>::MAX as u128;
+
+            #[inline]
+            fn checked_nth(self, step: Self, n: usize) -> Option<Self> {
+                // usize is at most 64 bits so all values involved fit in an i128
+                let offset = (step as i128).checked_mul(n as i128)?;
+                Self::try_from((self as i128).checked_add(offset)?).ok()
+            }
+
+            #[inline]
+            fn wrapping_nth(self, step: Self, n: usize) -> Self {
+                self.wrapping_add(step.wrapping_mul(n as Self))
+            }
+
+            #[inline]
+            fn from_i128(value: i128) -> Self {
+                value as Self
+            }
+        }
+    )*};
+}
+
+impl sealed::Sealed for u128 {}
+
+impl RangeInt for u128 {
+    const ONE: Self = 1;
+    const MAX_OFFSET: Self = Self::MAX;
+    const LOWER: i128 = 0;
+    const UPPER: Self = Self::MAX;
+
+    #[inline]
+    fn checked_nth(self, step: Self, n: usize) -> Option<Self> {
+        step.checked_mul(n as Self)?.checked_add(self)
+    }
+
+    #[inline]
+    fn wrapping_nth(self, step: Self, n: usize) -> Self {
+        self.wrapping_add(step.wrapping_mul(n as Self))
+    }
+
+    #[inline]
+    fn from_i128(value: i128) -> Self {
+        value as Self
+    }
+}
+
+impl sealed::Sealed for i128 {}
+
+impl RangeInt for i128 {
+    const ONE: Self = 1;
+    const MAX_OFFSET: u128 = Self::MAX.abs_diff(Self::MIN);
+    const LOWER: Self = Self::MIN;
+    const UPPER: u128 = Self::MAX as u128;
+
+    #[inline]
+    fn checked_nth(self, step: Self, n: usize) -> Option<Self> {
+        // `step * n` on its own may overflow even if the result fits, e.g. when stepping down
+        // from i128::MAX, so compare against the distance to the bound in the direction of step
+        let distance = if step < 0 {
+            self.abs_diff(Self::MIN)
+        } else {
+            self.abs_diff(Self::MAX)
+        };
+        if step.unsigned_abs().checked_mul(n as u128)? > distance {
+            return None;
+        }
+        Some(self.wrapping_nth(step, n))
+    }
+
+    #[inline]
+    fn wrapping_nth(self, step: Self, n: usize) -> Self {
+        self.wrapping_add(step.wrapping_mul(n as Self))
+    }
+
+    #[inline]
+    fn from_i128(value: i128) -> Self {
+        value
+    }
+}
+
+impl_range_int!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
+
+const fn fits(n: usize, max_offset: u128) -> bool {
+    n == 0 || (n - 1) as u128 <= max_offset
+}
+
+/// Whether `value` lies in `lower..=upper`
+const fn contains(value: i128, lower: i128, upper: u128) -> bool {
+    value >= lower && (value < 0 || value as u128 <= upper)
+}
+
+/// Whether `start`, `step` and `start + (n - 1) * step` all lie in `lower..=upper`
+const fn steps_fit(start: i128, step: i128, n: usize, lower: i128, upper: u128) -> bool {
+    if !contains(start, lower, upper) || !contains(step, lower, upper) {
+        return false;
+    }
+    if n == 0 {
+        return true;
+    }
+    // Distance to the bound in the direction of step, this can not overflow since start
+    // lies between the bounds
+    let distance = if step < 0 {
+        (start as u128).wrapping_sub(lower as u128)
+    } else {
+        upper.wrapping_sub(start as u128)
+    };
+    match step.unsigned_abs().checked_mul((n - 1) as u128) {
+        Some(offset) => offset <= distance,
+        None => false,
+    }
+}
+
+/// Creates a fixed size iterator over the `N` consecutive integers starting at `start`.
+///
+/// This is the fixed size counterpart of `start..start + N`. That `N` values fit in `T` is
+/// checked at compile time.
+///
+/// # Panics
+/// Panics if `start + (N - 1)` overflows `T`. This is checked once when the iterator is
+/// created. Use [`range_const`] to check this at compile time when `start` is a constant.
+///
+/// Basic usage:
+/// ```
+/// let indices: [usize; 4] = iter_fixed::range(0).collect();
+/// assert_eq!(indices, [0, 1, 2, 3]);
+///
+/// let res: [i8; 3] = iter_fixed::range(-1).rev().collect();
+/// assert_eq!(res, [1, 0, -1]);
+/// ```
+#[track_caller]
+#[inline]
+pub fn range<T: RangeInt, const N: usize>(start: T) -> IteratorFixed<Range<T>, N> {
+    const { const_assert(fits(N, T::MAX_OFFSET), "N values do not fit in T") };
+    range_step(start, T::ONE)
+}
+
+/// Creates a fixed size iterator over the `N` integers `start`, `start + step`,
+/// `start + 2 * step` and so on.
+///
+/// `step` may be negative for signed integers.
+///
+/// # Panics
+/// Panics if `start + (N - 1) * step` overflows `T`. This is checked once when the iterator
+/// is created.
+///
+/// Use [`range_step_const`] to check this at compile time when `start` and `step` are
+/// constants.
+///
+/// Basic usage:
+/// ```
+/// let res: [u32; 4] = iter_fixed::range_step(10, 5).collect();
+/// assert_eq!(res, [10, 15, 20, 25]);
+///
+/// let res: [i32; 3] = iter_fixed::range_step(1, -2).collect();
+/// assert_eq!(res, [1, -1, -3]);
+/// ```
+#[track_caller]
+#[inline]
+pub fn range_step<T: RangeInt, const N: usize>(start: T, step: T) -> IteratorFixed<Range<T>, N> {
+    if N > 0 {
+        assert!(
+            start.checked_nth(step, N - 1).is_some(),
+            "range overflows the integer type"
+        );
+    }
+    new_range(start, step)
+}
+
+/// Creates a fixed size iterator over the `N` consecutive integers starting at the constant
+/// `START`.
+///
+/// This is the same as [`range`], except that `START` and all `N` values are checked to fit in
+/// `T` at compile time. Since a const generic parameter can not have the generic type `T`,
+/// `START` is given as an `i128`, so `u128` values above `i128::MAX` need [`range`].
+///
+/// Basic usage:
+/// ```
+/// let indices: [usize; 4] = iter_fixed::range_const::<_, 0, 4>().collect();
+/// assert_eq!(indices, [0, 1, 2, 3]);
+///
+/// let res: [u8; 3] = iter_fixed::range_const::<_, 253, 3>().collect();
+/// assert_eq!(res, [253, 254, 255]);
+/// ```
+///
+/// An overflow fails to compile:
+/// ```compile_fail
+/// let res: [u8; 4] = iter_fixed::range_const::<_, 253, 4>().collect();
+/// ```
+#[inline]
+pub fn range_const<T: RangeInt, const START: i128, const N: usize>() -> IteratorFixed<Range<T>, N> {
+    range_step_const::<T, START, 1, N>()
+}
+
+/// Creates a fixed size iterator over the `N` integers `START`, `START + STEP`,
+/// `START + 2 * STEP` and so on.
+///
+/// This is the same as [`range_step`], except that `START`, `STEP` and all `N` values are
+/// checked to fit in `T` at compile time. Since a const generic parameter can not have the
+/// generic type `T`, `START` and `STEP` are given as `i128`, so `u128` values above
+/// `i128::MAX` need [`range_step`].
+///
+/// Basic usage:
+/// ```
+/// let res: [i8; 3] = iter_fixed::range_step_const::<_, 127, -127, 3>().collect();
+/// assert_eq!(res, [127, 0, -127]);
+/// ```
+///
+/// An overflow fails to compile:
+/// ```compile_fail
+/// let res: [i8; 4] = iter_fixed::range_step_const::<_, 127, -127, 4>().collect();
+/// ```
+#[inline]
+pub fn range_step_const<T: RangeInt, const START: i128, const STEP: i128, const N: usize>(
+) -> IteratorFixed<Range<T>, N> {
+    const {
+        const_assert(
+            steps_fit(START, STEP, N, T::LOWER, T::UPPER),
+            "range overflows the integer type",
+        )
+    };
+    new_range(T::from_i128(START), T::from_i128(STEP))
+}
+
+/// Creates the iterator once the range has been checked for overflow
+#[inline]
+fn new_range<T: RangeInt, const N: usize>(start: T, step: T) -> IteratorFixed<Range<T>, N> {
+    let range = Range {
+        start,
+        step,
+        front: 0,
+        back: N,
+    };
+    // Safety: Range yields one element for every index in 0..N
+    unsafe { IteratorFixed::from_iter(range) }
+}
+
+/// Iterator created by [`range`], [`range_step`] and their `const` counterparts
+#[derive(Clone, Debug)]
+pub struct Range<T> {
+    start: T,
+    step: T,
+    front: usize,
+    back: usize,
+}
+
+impl<T: RangeInt> Iterator for Range<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        // The last element has been checked not to overflow on creation
+        let value = self.start.wrapping_nth(self.step, self.front);
+        self.front += 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T: RangeInt> DoubleEndedIterator for Range<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        // The last element has been checked not to overflow on creation
+        Some(self.start.wrapping_nth(self.step, self.back))
+    }
+}
+
+impl<T: RangeInt> ExactSizeIterator for Range<T> {}
+
+impl<T: RangeInt> FusedIterator for Range<T> {}
@@ -0,0 +1,190 @@
+use core::{cmp::Ordering, mem::ManuallyDrop, ptr};
+
+/// Largest length that is sorted using a sorting network, anything longer falls back to
+/// [`slice::sort_unstable_by`]
+const MAX_NETWORK_LEN: usize = 32;
+
+/// Number of comparators in Batcher's odd-even merge sort network for `MAX_NETWORK_LEN`
+/// elements, which is an upper bound for all shorter networks
+const MAX_COMPARATORS: usize = 191;
+
+type Comparators = ([(u8, u8); MAX_COMPARATORS], usize);
+
+struct Network<const N: usize>;
+
+impl<const N: usize> Network<N> {
+    /// Comparators of the network for `N` elements, evaluated at compile time
+    const COMPARATORS: Comparators = batcher(N);
+}
+
+/// Builds Batcher's odd-even merge sort network for `n` elements
+///
+/// Returns an empty network for `n > MAX_NETWORK_LEN` since those are never sorted using a
+/// network.
+const fn batcher(n: usize) -> Comparators {
+    let mut comparators = [(0, 0); MAX_COMPARATORS];
+    let mut len = 0;
+    if n > MAX_NETWORK_LEN {
+        return (comparators, len);
+    }
+
+    let mut p = 1;
+    while p < n {
+        let mut k = p;
+        while k >= 1 {
+            let mut j = k % p;
+            while j + k < n {
+                let mut i = 0;
+                while i < k && i + j + k < n {
+                    if (i + j) / (2 * p) == (i + j + k) / (2 * p) {
+                        comparators[len] = ((i + j) as u8, (i + j + k) as u8);
+                        len += 1;
+                    }
+                    i += 1;
+                }
+                j += 2 * k;
+            }
+            k /= 2;
+        }
+        p *= 2;
+    }
+
+    (comparators, len)
+}
+
+/// Sorts `array` according to `compare`, this sort is not stable
+///
+/// Arrays of up to `MAX_NETWORK_LEN` elements are sorted using a sorting network which is
+/// fixed at compile time, longer arrays are sorted using [`slice::sort_unstable_by`].
+#[inline]
+pub(crate) fn sort_by<T, F, const N: usize>(array: &mut [T; N], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if N > MAX_NETWORK_LEN {
+        array.sort_unstable_by(compare);
+        return;
+    }
+
+    let (comparators, len) = &Network::<N>::COMPARATORS;
+    for &(a, b) in &comparators[..*len] {
+        // Safety: `batcher` only produces comparators with a < b < N
+        unsafe { compare_exchange(array, usize::from(a), usize::from(b), &mut compare) };
+    }
+}
+
+/// Swaps the elements at `a` and `b` if the element at `b` is less than the element at `a`
+///
+/// The swap is done by selecting the source of each of the two writes instead of branching on
+/// the comparison, which compiles to conditional moves for small `T`. The elements are only
+/// moved after `compare` has returned, so `array` is left intact if it panics.
+///
+/// # Safety
+/// Caller has to guarantee that `a < b < N`.
+#[inline]
+unsafe fn compare_exchange<T, F, const N: usize>(
+    array: &mut [T; N],
+    a: usize,
+    b: usize,
+    compare: &mut F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let base = array.as_mut_ptr();
+    // Safety: a and b are in bounds and differ, as guaranteed by the caller. The element
+    // written to `b` is read before `a` is overwritten, and `a` is only overwritten by itself
+    // or by the element at `b`, so every element ends up in exactly one of the two places
+    unsafe {
+        let (pa, pb) = (base.add(a), base.add(b));
+        let swap = compare(&*pb, &*pa) == Ordering::Less;
+
+        let to_a = if swap { pb } else { pa };
+        let to_b = if swap { pa } else { pb };
+        let tmp = ManuallyDrop::new(ptr::read(to_b));
+        ptr::copy(to_a, pa, 1);
+        ptr::copy_nonoverlapping(&*tmp, pb, 1);
+    }
+}
+
+/// Returns the indices that would sort `array` according to `compare`
+///
+/// Equal elements keep their relative order.
+#[inline]
+pub(crate) fn argsort_by<T, F, const N: usize>(array: &[T; N], mut compare: F) -> [usize; N]
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut indices = core::array::from_fn(|i| i);
+    sort_by(&mut indices, |&a, &b| {
+        compare(&array[a], &array[b]).then(a.cmp(&b))
+    });
+    indices
+}
+
+/// A function extracting a key to order elements of type `T` by
+///
+/// This is implemented for every `FnMut(&T) -> K` where `K: Ord`. It only exists so that the
+/// type of the key does not have to be a generic parameter of methods like
+/// [`IteratorFixed::top_k_by_key`](crate::IteratorFixed::top_k_by_key), which allows calling
+/// them as `top_k_by_key::<K>(f)`.
+pub trait KeyFn<T>: FnMut(&T) -> <Self as KeyFn<T>>::Key {
+    /// The type of the extracted keys
+    type Key: Ord;
+}
+
+impl<T, K: Ord, F: FnMut(&T) -> K> KeyFn<T> for F {
+    type Key = K;
+}
+
+/// Selects the `K` greatest elements of `iter` according to `compare`, in descending order
+///
+/// The elements are kept in a min-heap of `K` elements, so `iter` is never sorted as a whole.
+/// `iter` has to yield at least `K` elements.
+#[inline]
+pub(crate) fn select_greatest_by<I, F, const K: usize>(mut iter: I, mut compare: F) -> [I::Item; K]
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    let mut heap: [I::Item; K] = core::array::from_fn(|_| iter.next().unwrap());
+    for i in (0..K / 2).rev() {
+        sift_down(&mut heap, i, &mut compare);
+    }
+
+    for x in iter {
+        if K > 0 && compare(&x, &heap[0]) == Ordering::Greater {
+            heap[0] = x;
+            sift_down(&mut heap, 0, &mut compare);
+        }
+    }
+
+    sort_by(&mut heap, |a, b| compare(b, a));
+    heap
+}
+
+/// Restores the min-heap property of `heap` for the subtree rooted at `i`
+#[inline]
+fn sift_down<T, F>(heap: &mut [T], mut i: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    loop {
+        let left = 2 * i + 1;
+        let right = left + 1;
+        if left >= heap.len() {
+            return;
+        }
+
+        let child = if right < heap.len() && compare(&heap[right], &heap[left]) == Ordering::Less {
+            right
+        } else {
+            left
+        };
+
+        if compare(&heap[child], &heap[i]) != Ordering::Less {
+            return;
+        }
+        heap.swap(i, child);
+        i = child;
+    }
+}
@@ -0,0 +1,262 @@
+#[cfg(feature = "nightly_features")]
+use core::iter::TrustedRandomAccessNoCoerce;
+use core::{
+    iter::{self, FusedIterator},
+    mem,
+    ops::Range,
+};
+
+use crate::IteratorFixed;
+
+/// Creates a fixed size iterator that yields `value` exactly once.
+///
+/// See [`core::iter::once`]
+///
+/// Basic usage:
+/// ```
+/// let res: [_; 1] = iter_fixed::once(42).collect();
+///
+/// assert_eq!(res, [42]);
+/// ```
+#[inline]
+pub fn once<T>(value: T) -> IteratorFixed<iter::Once<T>, 1> {
+    // Safety: iter::once always yields 1 element
+    unsafe { IteratorFixed::from_iter(iter::once(value)) }
+}
+
+/// Creates a fixed size iterator that yields nothing.
+///
+/// See [`core::iter::empty`]
+///
+/// Basic usage:
+/// ```
+/// let res: [i32; 0] = iter_fixed::empty().collect();
+///
+/// assert_eq!(res, []);
+/// ```
+#[inline]
+pub fn empty<T>() -> IteratorFixed<iter::Empty<T>, 0> {
+    // Safety: iter::empty always yields 0 elements
+    unsafe { IteratorFixed::from_iter(iter::empty()) }
+}
+
+/// Creates a fixed size iterator that yields `value` `N` times.
+///
+/// See [`core::iter::repeat_n`]
+///
+/// Basic usage:
+/// ```
+/// let res: [_; 3] = iter_fixed::repeat_n(String::from("a")).collect();
+///
+/// assert_eq!(res, ["a", "a", "a"]);
+/// ```
+#[inline]
+pub fn repeat_n<T: Clone, const N: usize>(value: T) -> IteratorFixed<iter::RepeatN<T>, N> {
+    // Safety: iter::repeat_n(_, N) always yields N elements
+    unsafe { IteratorFixed::from_iter(iter::repeat_n(value, N)) }
+}
+
+/// Creates a fixed size iterator that yields `N` elements by calling `f` repeatedly.
+///
+/// See [`core::iter::repeat_with`]
+///
+/// Basic usage:
+/// ```
+/// let mut next = 1;
+/// let powers_of_two: [u32; 4] = iter_fixed::repeat_with(|| {
+///     let current = next;
+///     next *= 2;
+///     current
+/// })
+/// .collect();
+///
+/// assert_eq!(powers_of_two, [1, 2, 4, 8]);
+/// ```
+#[inline]
+pub fn repeat_with<T, F, const N: usize>(f: F) -> IteratorFixed<iter::Take<iter::RepeatWith<F>>, N>
+where
+    F: FnMut() -> T,
+{
+    // Safety: iter::repeat_with(_).take(N) always yields N elements
+    unsafe { IteratorFixed::from_iter(iter::repeat_with(f).take(N)) }
+}
+
+/// Creates a fixed size iterator that yields `first`, `f(&first)`, `f(&f(&first))` and so on,
+/// `N` elements in total.
+///
+/// `f` is only called to compute the elements that are yielded, so it is called `N - 1`
+/// times. The element after the current one is computed before the current one is yielded. If
+/// `f` panics, the current element is kept and the iterator still has the same length.
+///
+/// Basic usage:
+/// ```
+/// let powers_of_three: [u8; 5] = iter_fixed::iterate(1, |x| x * 3).collect();
+///
+/// assert_eq!(powers_of_three, [1, 3, 9, 27, 81]);
+/// ```
+#[inline]
+pub fn iterate<T, F, const N: usize>(first: T, f: F) -> IteratorFixed<Iterate<T, F>, N>
+where
+    F: FnMut(&T) -> T,
+{
+    let iterate = Iterate {
+        next: Some(first),
+        f,
+        remaining: N,
+    };
+    // Safety: Iterate yields an element for every one of the N remaining
+    unsafe { IteratorFixed::from_iter(iterate) }
+}
+
+/// Creates a fixed size iterator that yields `N` elements by calling `f` with a mutable
+/// reference to `state`.
+///
+/// Basic usage:
+/// ```
+/// let fibonacci: [u32; 6] = iter_fixed::unfold((0, 1), |(a, b)| {
+///     let current = *a;
+///     (*a, *b) = (*b, *a + *b);
+///     current
+/// })
+/// .collect();
+///
+/// assert_eq!(fibonacci, [0, 1, 1, 2, 3, 5]);
+/// ```
+#[inline]
+pub fn unfold<St, T, F, const N: usize>(state: St, f: F) -> IteratorFixed<Unfold<St, F>, N>
+where
+    F: FnMut(&mut St) -> T,
+{
+    let unfold = Unfold {
+        state,
+        f,
+        remaining: N,
+    };
+    // Safety: Unfold yields an element for every one of the N remaining
+    unsafe { IteratorFixed::from_iter(unfold) }
+}
+
+/// Iterator created by [`iterate`]
+#[derive(Clone)]
+pub struct Iterate<T, F> {
+    next: Option<T>,
+    f: F,
+    remaining: usize,
+}
+
+impl<T, F> Iterator for Iterate<T, F>
+where
+    F: FnMut(&T) -> T,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        // The current element stays in place until `f` has returned, so if `f` panics no
+        // element is lost and the remaining length is still correct
+        let after = if self.remaining > 1 {
+            Some((self.f)(self.next.as_ref()?))
+        } else {
+            None
+        };
+        self.remaining -= 1;
+        mem::replace(&mut self.next, after)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, F> ExactSizeIterator for Iterate<T, F> where F: FnMut(&T) -> T {}
+
+impl<T, F> FusedIterator for Iterate<T, F> where F: FnMut(&T) -> T {}
+
+/// Iterator created by [`unfold`]
+#[derive(Clone)]
+pub struct Unfold<St, F> {
+    state: St,
+    f: F,
+    remaining: usize,
+}
+
+impl<St, T, F> Iterator for Unfold<St, F>
+where
+    F: FnMut(&mut St) -> T,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some((self.f)(&mut self.state))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<St, T, F> ExactSizeIterator for Unfold<St, F> where F: FnMut(&mut St) -> T {}
+
+impl<St, T, F> FusedIterator for Unfold<St, F> where F: FnMut(&mut St) -> T {}
+
+/// Iterator created by [`from_fn`](crate::from_fn)
+#[derive(Clone)]
+pub struct FromFn<F> {
+    pub(crate) f: F,
+    pub(crate) indices: Range<usize>,
+}
+
+impl<T, F> Iterator for FromFn<F>
+where
+    F: FnMut(usize) -> T,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.indices.next().map(&mut self.f)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+
+    #[cfg(feature = "nightly_features")]
+    #[inline]
+    unsafe fn __iterator_get_unchecked(&mut self, idx: usize) -> T {
+        (self.f)(self.indices.start + idx)
+    }
+}
+
+impl<T, F> DoubleEndedIterator for FromFn<F>
+where
+    F: FnMut(usize) -> T,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.indices.next_back().map(&mut self.f)
+    }
+}
+
+impl<T, F> ExactSizeIterator for FromFn<F> where F: FnMut(usize) -> T {}
+
+impl<T, F> FusedIterator for FromFn<F> where F: FnMut(usize) -> T {}
+
+// Safety: `__iterator_get_unchecked` calls `f` with the index the element would have been
+// yielded for by `next()`, without advancing `indices`, so `size_hint` is unchanged
+#[cfg(feature = "nightly_features")]
+unsafe impl<F> TrustedRandomAccessNoCoerce for FromFn<F> {
+    const MAY_HAVE_SIDE_EFFECT: bool = true;
+}
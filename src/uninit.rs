@@ -0,0 +1,180 @@
+use core::{
+    marker::PhantomData,
+    mem::{self, MaybeUninit},
+    ptr,
+};
+
+use crate::random_access::SpecCollect;
+use crate::IteratorFixed;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+/// Drops the first `initialized` elements of `slots` when dropped
+///
+/// This cleans up the elements written so far if writing the remaining ones panics.
+pub(crate) struct Guard<'a, T> {
+    pub(crate) slots: &'a mut [MaybeUninit<T>],
+    pub(crate) initialized: usize,
+}
+
+impl<T> Drop for Guard<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        let initialized =
+            ptr::slice_from_raw_parts_mut(self.slots.as_mut_ptr().cast::<T>(), self.initialized);
+        // Safety: The first `initialized` slots have been written to
+        unsafe { ptr::drop_in_place(initialized) }
+    }
+}
+
+/// Moves the elements of `iter_fixed` into the first `N` of `slots`
+///
+/// After this returns, the first `N` slots are initialized. If `iter_fixed` panics the
+/// elements written so far are dropped and `slots` is left uninitialized.
+///
+/// # Panics
+/// Panics if `slots` is shorter than `N`, before any element is written.
+#[inline]
+pub(crate) fn write_slots<T, I: Iterator<Item = T>, const N: usize>(
+    slots: &mut [MaybeUninit<T>],
+    iter_fixed: IteratorFixed<I, N>,
+) {
+    let slots = &mut slots[..N];
+
+    // Safety: We know that the iterator yields N elements due to it originating from an
+    // IteratorFixed of size N, which every source guarantees as part of the contract of
+    // `IntoIteratorFixed` and `IteratorFixed::from_iter`. That contract covers clones of
+    // the iterator as well, so this also holds for a cloned IteratorFixed
+    unsafe { iter_fixed.inner.spec_write_slots(slots) }
+}
+
+/// Collects the elements of `iter_fixed` into an array
+///
+/// If `iter_fixed` panics the elements yielded so far are dropped.
+#[inline]
+pub(crate) fn collect_array<T, I: Iterator<Item = T>, const N: usize>(
+    iter_fixed: IteratorFixed<I, N>,
+) -> [T; N] {
+    // Safety: The iterator yields N elements, see `write_slots`
+    unsafe { iter_fixed.inner.spec_collect_array() }
+}
+
+/// Moves the elements of `iter` into `slots` by calling `next()`, see
+/// [`SpecCollect::spec_write_slots`]
+///
+/// # Safety
+/// `iter` has to yield at least `slots.len()` elements.
+#[inline]
+pub(crate) unsafe fn write_in_order<I: Iterator>(mut iter: I, slots: &mut [MaybeUninit<I::Item>]) {
+    let mut guard = Guard {
+        slots,
+        initialized: 0,
+    };
+
+    for slot in guard.slots.iter_mut() {
+        // Safety: The caller guarantees that there are enough elements
+        slot.write(unsafe { iter.next().unwrap_unchecked() });
+        guard.initialized += 1;
+    }
+    mem::forget(guard);
+}
+
+/// Collects the elements of `iter` into an array by calling `next()`, see
+/// [`SpecCollect::spec_collect_array`]
+///
+/// # Safety
+/// `iter` has to yield at least `N` elements.
+#[inline]
+pub(crate) unsafe fn collect_in_order<I: Iterator, const N: usize>(iter: I) -> [I::Item; N] {
+    let mut array = [const { MaybeUninit::uninit() }; N];
+    // Safety: Forwarded from the caller
+    unsafe { write_in_order(iter, &mut array) };
+
+    // Safety: All N elements of `array` have been initialized and MaybeUninit<T> has the
+    // same layout as T
+    unsafe { array.as_ptr().cast::<[I::Item; N]>().read() }
+}
+
+/// Drops the mapped and the not yet mapped elements of an array that is mapped in place
+///
+/// The elements before `index` are already mapped, the element at `index` has been moved out
+/// and the elements after it are still unmapped.
+struct MapGuard<T, U, const N: usize> {
+    ptr: *mut T,
+    index: usize,
+    _marker: PhantomData<U>,
+}
+
+impl<T, U, const N: usize> Drop for MapGuard<T, U, N> {
+    #[inline]
+    fn drop(&mut self) {
+        // Safety: The element ranges are initialized with U and T respectively as described
+        // above
+        unsafe {
+            let mapped = ptr::slice_from_raw_parts_mut(self.ptr.cast::<U>(), self.index);
+            ptr::drop_in_place(mapped);
+            let unmapped =
+                ptr::slice_from_raw_parts_mut(self.ptr.add(self.index + 1), N - self.index - 1);
+            ptr::drop_in_place(unmapped);
+        }
+    }
+}
+
+/// Maps the `N` elements starting at `ptr` with `f`, writing every result back to the place
+/// of its input
+///
+/// If `f` panics, both the already mapped and the remaining elements are dropped.
+///
+/// # Safety
+/// `ptr` has to point to `N` initialized elements that are owned by the caller and are not
+/// read or dropped as `T` afterwards. `T` and `U` have to have the same size and alignment.
+#[inline]
+pub(crate) unsafe fn map_elements<T, U, F, const N: usize>(ptr: *mut T, mut f: F)
+where
+    F: FnMut(T) -> U,
+{
+    let mut guard = MapGuard::<T, U, N> {
+        ptr,
+        index: 0,
+        _marker: PhantomData,
+    };
+
+    for i in 0..N {
+        guard.index = i;
+        // Safety: Element i is still unmapped and T and U have the same layout, so element i
+        // can hold a U once it is moved out
+        unsafe {
+            let x = ptr.add(i).read();
+            ptr.add(i).cast::<U>().write(f(x));
+        }
+    }
+    mem::forget(guard);
+}
+
+/// Maps every element of the boxed array `array` with `f`, reusing its allocation
+///
+/// If `f` panics, both the already mapped and the remaining elements are dropped and the
+/// allocation is freed.
+///
+/// # Safety
+/// All N elements of `array` have to be initialized and `T` and `U` have to have the same
+/// size and alignment.
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) unsafe fn map_in_place<T, U, F, const N: usize>(
+    mut array: Box<[MaybeUninit<T>; N]>,
+    f: F,
+) -> Box<[U; N]>
+where
+    F: FnMut(T) -> U,
+{
+    // `array` only frees the allocation when dropped, the elements are dropped by
+    // `map_elements` if `f` panics
+    // Safety: Guaranteed by the caller
+    unsafe { map_elements::<T, U, F, N>(array.as_mut_ptr().cast::<T>(), f) };
+
+    // Safety: All N elements have been mapped and [U; N] has the same layout as the
+    // [MaybeUninit<T>; N] that was allocated
+    unsafe { Box::from_raw(Box::into_raw(array).cast::<[U; N]>()) }
+}
@@ -0,0 +1,94 @@
+use core::iter::Fuse;
+
+/// A value of either or both of two iterators, yielded by
+/// [`crate::IteratorFixed::zip_longest_exact`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EitherOrBoth<A, B> {
+    /// Both iterators yielded a value
+    Both(A, B),
+
+    /// Only the left iterator yielded a value
+    Left(A),
+
+    /// Only the right iterator yielded a value
+    Right(B),
+}
+
+impl<A, B> EitherOrBoth<A, B> {
+    /// Returns the left value, if any
+    #[inline]
+    pub fn left(self) -> Option<A> {
+        match self {
+            Self::Both(a, _) | Self::Left(a) => Some(a),
+            Self::Right(_) => None,
+        }
+    }
+
+    /// Returns the right value, if any
+    #[inline]
+    pub fn right(self) -> Option<B> {
+        match self {
+            Self::Both(_, b) | Self::Right(b) => Some(b),
+            Self::Left(_) => None,
+        }
+    }
+
+    /// Returns both values, using `a` or `b` in place of a missing value
+    ///
+    /// Basic usage:
+    /// ```
+    /// use iter_fixed::EitherOrBoth;
+    ///
+    /// assert_eq!(EitherOrBoth::<i32, i32>::Left(1).or(0, 0), (1, 0));
+    /// assert_eq!(EitherOrBoth::<i32, i32>::Both(1, 2).or(0, 0), (1, 2));
+    /// ```
+    #[inline]
+    pub fn or(self, a: A, b: B) -> (A, B) {
+        match self {
+            Self::Both(a, b) => (a, b),
+            Self::Left(a) => (a, b),
+            Self::Right(b) => (a, b),
+        }
+    }
+}
+
+/// Iterator zipping two iterators until both are exhausted, see
+/// [`crate::IteratorFixed::zip_longest_exact`]
+pub(crate) struct ZipLongest<A, B> {
+    a: Fuse<A>,
+    b: Fuse<B>,
+}
+
+impl<A: Iterator, B: Iterator> ZipLongest<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self {
+            a: a.fuse(),
+            b: b.fuse(),
+        }
+    }
+}
+
+impl<A: Iterator, B: Iterator> Iterator for ZipLongest<A, B> {
+    type Item = EitherOrBoth<A::Item, B::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.a.next(), self.b.next()) {
+            (Some(a), Some(b)) => Some(EitherOrBoth::Both(a, b)),
+            (Some(a), None) => Some(EitherOrBoth::Left(a)),
+            (None, Some(b)) => Some(EitherOrBoth::Right(b)),
+            (None, None) => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            _ => None,
+        };
+        (a_lower.max(b_lower), upper)
+    }
+}
@@ -0,0 +1,13 @@
+// The exact compiler output differs between toolchains, so this test is `#[ignore]`d and the
+// messages are only checked by the "Compiler messages" job in CI, which runs it on Rust 1.95.0
+// without the nightly features. After changing a diagnostic or anything else that shows up in
+// the messages, update tests/ui/*.stderr with
+// `TRYBUILD=overwrite cargo +1.95.0 test --test compile_fail -- --ignored`
+// and check them with the same command without `TRYBUILD=overwrite`.
+#[cfg(not(feature = "nightly_features"))]
+#[ignore = "compiler output is only checked on the toolchain pinned in CI"]
+#[test]
+fn length_mismatch() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -70,3 +70,940 @@ fn test_changing_length() {
 
     assert_eq!(res, [1, 1, 2, 2, 3, 3]);
 }
+
+fn check_sorted<const N: usize>() {
+    // By the 0-1 principle a sorting network that sorts every sequence of zeros and ones
+    // sorts every sequence
+    for bits in 0u32..1 << N {
+        let res: [_; N] = iter_fixed::from_fn(|i| (bits >> i) & 1).sorted().collect();
+        let ones = bits.count_ones() as usize;
+        assert!(res[..N - ones].iter().all(|&x| x == 0));
+        assert!(res[N - ones..].iter().all(|&x| x == 1));
+    }
+}
+
+#[test]
+fn test_sorted() {
+    check_sorted::<1>();
+    check_sorted::<2>();
+    check_sorted::<3>();
+    check_sorted::<5>();
+    check_sorted::<8>();
+    check_sorted::<9>();
+    check_sorted::<13>();
+    check_sorted::<16>();
+
+    let res: [_; 32] = iter_fixed::from_fn(|i| (i * 7) % 32).sorted().collect();
+    assert_eq!(res, iter_fixed::from_fn(|i| i).collect::<[_; 32]>());
+
+    let res: [_; 100] = iter_fixed::from_fn(|i| (i * 37) % 100)
+        .sorted_by(|a, b| b.cmp(a))
+        .collect();
+    assert_eq!(res, iter_fixed::from_fn(|i| 99 - i).collect::<[_; 100]>());
+
+    let res: [_; 0] = [0u8; 0].into_iter_fixed().sorted().collect();
+    assert_eq!(res, []);
+}
+
+#[test]
+fn test_sorted_owned() {
+    use std::{panic, rc::Rc};
+
+    let words = ["pear", "fig", "apple", "kiwi", "date"].map(String::from);
+    let res: [_; 5] = words.into_iter_fixed().sorted().collect();
+    assert_eq!(res, ["apple", "date", "fig", "kiwi", "pear"]);
+
+    // Every element is still dropped exactly once if the comparison panics
+    let values: [_; 9] = iter_fixed::from_fn(|i| Rc::new((i * 4) % 9)).collect();
+    let mut comparisons = 0;
+    let res = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        let _: [_; 9] = values
+            .clone()
+            .into_iter_fixed()
+            .sorted_by(|a, b| {
+                comparisons += 1;
+                assert!(comparisons < 10);
+                a.cmp(b)
+            })
+            .collect();
+    }));
+    assert!(res.is_err());
+    assert!(values.iter().all(|x| Rc::strong_count(x) == 1));
+}
+
+#[test]
+fn test_argsort() {
+    let values = [3, 1, 4, 1, 5, 9, 2, 6];
+
+    let order: [usize; 8] = (&values).into_iter_fixed().argsort().collect();
+    assert_eq!(order, [1, 3, 6, 0, 2, 4, 7, 5]);
+
+    let ranks: [usize; 8] = (&values).into_iter_fixed().rank().collect();
+    for (rank, &i) in order.iter().enumerate() {
+        assert_eq!(ranks[i], rank);
+    }
+
+    let sorted: [_; 8] = order.into_iter_fixed().map(|i| values[i]).collect();
+    assert_eq!(sorted, [1, 1, 2, 3, 4, 5, 6, 9]);
+}
+
+#[test]
+fn test_top_k() {
+    let values: [u32; 20] = iter_fixed::from_fn(|i| (i as u32 * 13) % 20).collect();
+
+    let res: [_; 5] = values.into_iter_fixed().top_k::<5>().collect();
+    assert_eq!(res, [19, 18, 17, 16, 15]);
+
+    let res: [_; 5] = values.into_iter_fixed().bottom_k::<5>().collect();
+    assert_eq!(res, [0, 1, 2, 3, 4]);
+
+    let res: [_; 20] = values.into_iter_fixed().top_k::<20>().collect();
+    assert_eq!(
+        res,
+        iter_fixed::from_fn(|i| 19 - i as u32).collect::<[_; 20]>()
+    );
+
+    let res: [u32; 0] = values.into_iter_fixed().top_k::<0>().collect();
+    assert_eq!(res, []);
+
+    // Distance to 10, the key type is inferred from the closure
+    let res: [_; 3] = values
+        .into_iter_fixed()
+        .bottom_k_by_key::<3>(|&x| x.abs_diff(10))
+        .collect();
+    assert_eq!(res[0], 10);
+    assert_eq!(
+        res[1..].iter().map(|x| x.abs_diff(10)).collect::<Vec<_>>(),
+        [1, 1]
+    );
+
+    let res: [_; 2] = values
+        .into_iter_fixed()
+        .top_k_by_key::<2>(|&x| x % 7)
+        .map(|x| x % 7)
+        .collect();
+    assert_eq!(res, [6, 6]);
+}
+
+#[test]
+fn test_merge() {
+    let res: [_; 7] = [1, 3, 5, 7]
+        .into_iter_fixed()
+        .merge_exact([2, 3, 8])
+        .collect();
+    assert_eq!(res, [1, 2, 3, 3, 5, 7, 8]);
+
+    // Unsorted input still yields every element exactly once
+    let mut res: [_; 6] = [9, 1, 5].into_iter_fixed().merge_exact([4, 8, 0]).collect();
+    res.sort();
+    assert_eq!(res, [0, 1, 4, 5, 8, 9]);
+
+    let res: [_; 2] = [0u8; 0].into_iter_fixed().merge_exact([1, 2]).collect();
+    assert_eq!(res, [1, 2]);
+}
+
+#[cfg(feature = "nightly_features")]
+#[test]
+fn test_merge_nightly() {
+    let res: [_; 5] = [1, 3, 4].into_iter_fixed().merge([2, 5]).collect();
+    assert_eq!(res, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_gather_permute_scatter() {
+    let values = [1, 2, 3, 4];
+
+    let res: [_; 6] = values
+        .into_iter_fixed()
+        .gather([3, 3, 2, 1, 0, 0])
+        .collect();
+    assert_eq!(res, [4, 4, 3, 2, 1, 1]);
+
+    let res: [_; 4] = values.into_iter_fixed().permute([1, 3, 0, 2]).collect();
+    assert_eq!(res, [2, 4, 1, 3]);
+
+    let mut dest = [0; 4];
+    values
+        .into_iter_fixed()
+        .scatter_into(&mut dest, [1, 3, 0, 2]);
+    assert_eq!(dest, [3, 1, 4, 2]);
+}
+
+#[test]
+#[should_panic(expected = "invalid permutation")]
+fn test_permute_duplicate_index() {
+    let _ = [1, 2, 3].into_iter_fixed().permute([0, 1, 1]);
+}
+
+#[test]
+#[should_panic(expected = "gather index out of bounds")]
+fn test_gather_out_of_bounds() {
+    let _ = [1, 2, 3].into_iter_fixed().gather([3]);
+}
+
+#[test]
+fn test_select() {
+    use std::rc::Rc;
+
+    let a = Rc::new(1);
+    let b = Rc::new(2);
+    let mask = [true, false, true];
+
+    let res: [_; 3] = [a.clone(), a.clone(), a.clone()]
+        .into_iter_fixed()
+        .select(mask, [b.clone(), b.clone(), b.clone()])
+        .collect();
+    assert_eq!(res, [a.clone(), b.clone(), a.clone()]);
+
+    // The elements not selected have been dropped
+    assert_eq!(Rc::strong_count(&a), 3);
+    assert_eq!(Rc::strong_count(&b), 2);
+
+    assert_eq!(mask.into_iter_fixed().count_ones(), 2);
+    assert_eq!(mask.into_iter_fixed().count_zeros(), 1);
+}
+
+#[test]
+fn test_histogram() {
+    let samples: [u8; 16] = iter_fixed::from_fn(|i| (i * 5) as u8).collect();
+
+    let histogram = samples
+        .into_iter_fixed()
+        .histogram::<4>(|&x| x as usize / 16);
+    assert_eq!(histogram.buckets, [4, 3, 3, 3]);
+    assert_eq!(histogram.overflow, 3);
+
+    let groups = samples
+        .into_iter_fixed()
+        .group_into::<2, Vec<u8>>(|&x| x as usize % 2, |group, x| group.push(x));
+    assert_eq!(groups.buckets[0], [0, 10, 20, 30, 40, 50, 60, 70]);
+    assert_eq!(groups.buckets[1], [5, 15, 25, 35, 45, 55, 65, 75]);
+    assert_eq!(groups.overflow, 0);
+}
+
+#[test]
+fn test_structural_edits() {
+    let res: [_; 4] = [1, 2, 3]
+        .into_iter_fixed()
+        .insert_exact::<0, 4>(0)
+        .collect();
+    assert_eq!(res, [0, 1, 2, 3]);
+
+    let res: [_; 4] = [1, 2, 3]
+        .into_iter_fixed()
+        .insert_exact::<3, 4>(4)
+        .collect();
+    assert_eq!(res, [1, 2, 3, 4]);
+
+    let (removed, rest) = [1, 2, 3].into_iter_fixed().remove_exact::<0, 2>();
+    assert_eq!(removed, 1);
+    assert_eq!(rest.collect::<[_; 2]>(), [2, 3]);
+
+    let (removed, rest) = [1, 2, 3].into_iter_fixed().remove_exact::<2, 2>();
+    assert_eq!(removed, 3);
+    assert_eq!(rest.collect::<[_; 2]>(), [1, 2]);
+
+    let res: [_; 3] = [1, 2, 3].into_iter_fixed().replace::<2>(7).collect();
+    assert_eq!(res, [1, 2, 7]);
+
+    let res: [_; 3] = [1, 2, 3].into_iter_fixed().swap::<1, 1>().collect();
+    assert_eq!(res, [1, 2, 3]);
+}
+
+#[cfg(feature = "nightly_features")]
+#[test]
+fn test_structural_edits_nightly() {
+    let res: [_; 4] = [1, 2, 3].into_iter_fixed().insert::<1>(4).collect();
+    assert_eq!(res, [1, 4, 2, 3]);
+
+    let (removed, rest) = [1, 2, 3].into_iter_fixed().remove::<1>();
+    let res: [_; 2] = rest.collect();
+    assert_eq!((removed, res), (2, [1, 3]));
+}
+
+#[test]
+fn test_split_at() {
+    let (head, tail) = [1, 2, 3].into_iter_fixed().split_at_exact::<0, 3>();
+    assert_eq!(head.collect::<[i32; 0]>(), []);
+    assert_eq!(tail.collect::<[_; 3]>(), [1, 2, 3]);
+
+    let (head, tail) = [1, 2, 3].into_iter_fixed().split_at_exact::<3, 0>();
+    assert_eq!(head.collect::<[_; 3]>(), [1, 2, 3]);
+    assert_eq!(tail.collect::<[i32; 0]>(), []);
+
+    let (low, high) = [0, 2, 4, 6].into_iter_fixed().split_at_exact::<2, 2>();
+    let res: [_; 2] = low.zip(high).map(|(a, b)| a + b).collect();
+    assert_eq!(res, [4, 8]);
+
+    let values = [String::from("a"), String::from("b"), String::from("c")];
+    let res = values
+        .into_iter_fixed()
+        .split_array_at_exact::<1, 2, _>(|head, tail| {
+            // The element that is not yielded is dropped with its iterator
+            let last = tail.into_iter().next_back();
+            (head.collect::<[_; 1]>(), last)
+        });
+    assert_eq!(res, (["a".to_string()], Some("c".to_string())));
+
+    let res = [1, 2, 3]
+        .into_iter_fixed()
+        .split_array_at_exact::<0, 3, _>(|head, tail| {
+            (head.collect::<[i32; 0]>(), tail.collect::<[_; 3]>())
+        });
+    assert_eq!(res, ([], [1, 2, 3]));
+}
+
+#[cfg(feature = "nightly_features")]
+#[test]
+fn test_split_at_nightly() {
+    let (head, tail) = [1, 2, 3].into_iter_fixed().split_at::<1>();
+    let res: ([_; 1], [_; 2]) = (head.collect(), tail.collect());
+    assert_eq!(res, ([1], [2, 3]));
+}
+
+#[test]
+fn test_rotate() {
+    let values = [String::from("a"), String::from("b"), String::from("c")];
+
+    let res: [_; 3] = values
+        .clone()
+        .into_iter_fixed()
+        .rotate_left::<4>()
+        .collect();
+    assert_eq!(res, ["b", "c", "a"]);
+
+    let res: [_; 3] = values.into_iter_fixed().rotate_right::<3>().collect();
+    assert_eq!(res, ["a", "b", "c"]);
+
+    let res: [i32; 0] = [].into_iter_fixed().rotate_left::<1>().collect();
+    assert_eq!(res, []);
+}
+
+#[test]
+fn test_zip_longest() {
+    use iter_fixed::EitherOrBoth::{Both, Left};
+
+    let res: [_; 3] = [1, 2, 3]
+        .into_iter_fixed()
+        .zip_longest_exact(['a'])
+        .collect();
+    assert_eq!(res, [Both(1, 'a'), Left(2), Left(3)]);
+
+    let res: [_; 2] = [1, 2]
+        .into_iter_fixed()
+        .zip_longest_exact(['a', 'b'])
+        .collect();
+    assert_eq!(res, [Both(1, 'a'), Both(2, 'b')]);
+
+    let res: [(i32, char); 0] = [1, 2].into_iter_fixed().zip_shortest_exact([]).collect();
+    assert_eq!(res, []);
+}
+
+#[cfg(feature = "nightly_features")]
+#[test]
+fn test_zip_longest_nightly() {
+    let res: [_; 3] = [1, 2]
+        .into_iter_fixed()
+        .zip_longest([3, 4, 5])
+        .map(|x| x.or(0, 0))
+        .collect();
+    assert_eq!(res, [(1, 3), (2, 4), (0, 5)]);
+
+    let res: [_; 2] = [1, 2].into_iter_fixed().zip_shortest([3, 4, 5]).collect();
+    assert_eq!(res, [(1, 3), (2, 4)]);
+}
+
+#[test]
+fn test_zip_runtime_iterator() {
+    let res: [_; 3] = [1, 2, 3]
+        .into_iter_fixed()
+        .zip_padded(core::iter::empty(), 0)
+        .collect();
+    assert_eq!(res, [(1, 0), (2, 0), (3, 0)]);
+
+    let res: [_; 3] = [1, 2, 3].into_iter_fixed().zip_padded(4.., 0).collect();
+    assert_eq!(res, [(1, 4), (2, 5), (3, 6)]);
+
+    let res: [_; 3] = [1, 2, 3]
+        .into_iter_fixed()
+        .zip_checked(4..7)
+        .unwrap()
+        .collect();
+    assert_eq!(res, [(1, 4), (2, 5), (3, 6)]);
+
+    let err = [1, 2, 3].into_iter_fixed().zip_checked(4..6).err().unwrap();
+    assert_eq!(
+        err,
+        iter_fixed::LengthError {
+            expected: 3,
+            actual: 2
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "expected an iterator of at least 3 elements but it yielded 2"
+    );
+}
+
+#[test]
+fn test_sources() {
+    let res: [_; 1] = iter_fixed::once(1).collect();
+    assert_eq!(res, [1]);
+
+    let res: [u8; 0] = iter_fixed::empty().collect();
+    assert_eq!(res, []);
+
+    let res: [_; 3] = iter_fixed::repeat_n(7).collect();
+    assert_eq!(res, [7, 7, 7]);
+
+    // `f` is not called past the last element, which would overflow here
+    let res: [u8; 8] = iter_fixed::iterate(1, |x| x * 2).collect();
+    assert_eq!(res, [1, 2, 4, 8, 16, 32, 64, 128]);
+
+    let res: [u8; 0] = iter_fixed::iterate(1, |_| unreachable!()).collect();
+    assert_eq!(res, []);
+
+    // A panic in `f` neither loses the current element nor changes the length
+    let fail = std::cell::Cell::new(true);
+    let mut it = iter_fixed::iterate::<_, _, 3>(String::from("a"), |s| {
+        assert!(!fail.get());
+        s.repeat(2)
+    })
+    .into_iter();
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| it.next()));
+    assert!(res.is_err());
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    fail.set(false);
+    assert_eq!(it.collect::<Vec<_>>(), ["a", "aa", "aaaa"]);
+
+    let geometric = iter_fixed::iterate::<_, _, 3>(1.0, |x| x * 0.5);
+    let res: [(f64, f64); 3] = geometric.clone().zip(geometric).collect();
+    assert_eq!(res, [(1.0, 1.0), (0.5, 0.5), (0.25, 0.25)]);
+
+    let res: [_; 4] = iter_fixed::unfold(10, |x| {
+        *x -= 1;
+        *x
+    })
+    .collect();
+    assert_eq!(res, [9, 8, 7, 6]);
+}
+
+#[test]
+fn test_range() {
+    let res: [u8; 256] = iter_fixed::range(0).collect();
+    assert_eq!(res[255], 255);
+
+    let res: [i8; 256] = iter_fixed::range(i8::MIN).rev().collect();
+    assert_eq!((res[0], res[255]), (i8::MAX, i8::MIN));
+
+    let res: [i8; 3] = iter_fixed::range_step(i8::MAX, -127).collect();
+    assert_eq!(res, [127, 0, -127]);
+
+    let res: [u128; 2] = iter_fixed::range_step(u128::MAX - 1, 1).collect();
+    assert_eq!(res, [u128::MAX - 1, u128::MAX]);
+
+    let res: [u64; 0] = iter_fixed::range_step(u64::MAX, u64::MAX).collect();
+    assert_eq!(res, []);
+
+    let res: [i128; 4] = iter_fixed::range_step(i128::MAX, -(1 << 126)).collect();
+    assert_eq!(res[3], -(1 << 126) - 1);
+
+    let res: [i128; 4] = iter_fixed::range_step(i128::MIN, 1 << 126).collect();
+    assert_eq!(res[3], 1 << 126);
+
+    let res: [u8; 3] = iter_fixed::range_const::<_, 253, 3>().collect();
+    assert_eq!(res, [253, 254, 255]);
+
+    let res: [i128; 4] = iter_fixed::range_step_const::<_, { i128::MAX }, { -(1 << 126) }, 4>()
+        .rev()
+        .collect();
+    assert_eq!(res[0], -(1 << 126) - 1);
+
+    let res: [u32; 0] = iter_fixed::range_step_const::<_, 0, 7, 0>().collect();
+    assert_eq!(res, []);
+}
+
+#[test]
+#[should_panic(expected = "range overflows the integer type")]
+fn test_range_overflow_i128() {
+    let _: [i128; 5] = iter_fixed::range_step(i128::MAX, -(1 << 126)).collect();
+}
+
+#[test]
+#[should_panic(expected = "range overflows the integer type")]
+fn test_range_overflow() {
+    let _: [u8; 3] = iter_fixed::range(254).collect();
+}
+
+#[test]
+fn test_tuples() {
+    let gamma = |x: f32| x * x;
+    let rgb: (f32, f32, f32) = (0.5, 1.0, 0.0).into_iter_fixed().map(gamma).collect();
+    assert_eq!(rgb, (0.25, 1.0, 0.0));
+
+    let point = (1, 2);
+    let res: (i32, i32) = (&point)
+        .into_iter_fixed()
+        .zip((3, 4))
+        .map(|(a, b)| a + b)
+        .collect();
+    assert_eq!(res, (4, 6));
+
+    let mut point = (1, 2);
+    (&mut point)
+        .into_iter_fixed()
+        .into_iter()
+        .for_each(|x| *x *= 10);
+    assert_eq!(point, (10, 20));
+
+    let res: (u8,) = [1].into_iter_fixed().collect();
+    assert_eq!(res, (1,));
+
+    let res: [_; 12] = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)
+        .into_iter_fixed()
+        .collect();
+    assert_eq!(res, iter_fixed::range::<i32, 12>(0).collect::<[_; 12]>());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_alloc() {
+    use std::{rc::Rc, sync::Arc};
+
+    let v: Vec<_> = [1, 2, 3].into_iter_fixed().collect();
+    assert_eq!((v.len(), v.capacity()), (3, 3));
+
+    let b: Box<[_]> = [1, 2, 3].into_iter_fixed().collect();
+    assert_eq!(*b, [1, 2, 3]);
+
+    let b: Box<[String; 2]> = [String::from("a"), String::from("b")]
+        .into_iter_fixed()
+        .collect();
+    let res: [_; 2] = b.into_iter_fixed().rev().collect();
+    assert_eq!(res, ["b", "a"]);
+
+    let rc: Rc<[_]> = [1, 2, 3].into_iter_fixed().collect();
+    assert_eq!(*rc, [1, 2, 3]);
+
+    let arc: Arc<[i32]> = iter_fixed::empty().collect();
+    assert_eq!(*arc, []);
+}
+
+#[test]
+fn test_fill_existing_storage() {
+    use core::mem::MaybeUninit;
+    use std::rc::Rc;
+
+    let old = Rc::new(0);
+    let mut dest = [old.clone(), old.clone()];
+    let new: &mut [_; 2] = [Rc::new(1), Rc::new(2)]
+        .into_iter_fixed()
+        .collect_into(&mut dest);
+    assert_eq!(new, &[Rc::new(1), Rc::new(2)]);
+    assert_eq!(Rc::strong_count(&old), 1);
+
+    let mut dest = (0, 0, 0);
+    [1, 2, 3].into_iter_fixed().collect_into(&mut dest);
+    assert_eq!(dest, (1, 2, 3));
+
+    let mut storage = [const { MaybeUninit::uninit() }; 2];
+    let values = [Rc::new(1), Rc::new(2)];
+    let res = (&values)
+        .into_iter_fixed()
+        .cloned()
+        .write_uninit(&mut storage);
+    assert_eq!(res, &values);
+    assert_eq!(Rc::strong_count(&values[0]), 2);
+    unsafe { core::ptr::drop_in_place(res) };
+    assert_eq!(Rc::strong_count(&values[0]), 1);
+}
+
+#[test]
+fn test_write_uninit_panic() {
+    use core::mem::MaybeUninit;
+    use std::{panic, rc::Rc};
+
+    let value = Rc::new(0);
+    let mut storage = [const { MaybeUninit::uninit() }; 3];
+    let res = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        iter_fixed::from_fn(|i| {
+            assert!(i < 2);
+            value.clone()
+        })
+        .write_uninit(&mut storage);
+    }));
+    assert!(res.is_err());
+
+    // The two elements written before the panic have been dropped
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn test_collect_array_panic() {
+    use std::{cell::Cell, panic};
+
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let res = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        let _: [DropCounter; 4] = iter_fixed::from_fn(|i| {
+            assert!(i < 3);
+            DropCounter(&drops)
+        })
+        .collect();
+    }));
+    assert!(res.is_err());
+
+    // The three elements created before the panic have been dropped exactly once
+    assert_eq!(drops.get(), 3);
+
+    let a: [DropCounter; 4] = iter_fixed::from_fn(|_| DropCounter(&drops)).collect();
+    assert_eq!(drops.get(), 3);
+    drop(a);
+    assert_eq!(drops.get(), 7);
+}
+
+#[test]
+fn test_clone_keeps_length() {
+    // Collecting a clone relies on it yielding N elements just like the original
+    let strings = [1, 2, 3].map(|x| x.to_string());
+    let it = strings.clone().into_iter_fixed();
+    assert_eq!(it.clone().collect::<[String; 3]>(), strings);
+    assert_eq!(it.collect::<[String; 3]>(), strings);
+
+    let it = iter_fixed::once(String::from("a")).zip(iter_fixed::repeat_n(String::from("b")));
+    assert_eq!(it.clone().collect::<[_; 1]>(), it.collect::<[_; 1]>());
+
+    let it = iter_fixed::iterate::<_, _, 4>(String::from("a"), |s| s.repeat(2));
+    assert_eq!(it.clone().collect::<[_; 4]>(), it.collect::<[_; 4]>());
+
+    let it = iter_fixed::unfold::<_, _, _, 3>(1, |x| {
+        *x *= 3;
+        x.to_string()
+    });
+    assert_eq!(it.clone().collect::<[_; 3]>(), ["3", "9", "27"]);
+    assert_eq!(it.collect::<[_; 3]>(), ["3", "9", "27"]);
+
+    let it = iter_fixed::range::<u8, 4>(250).enumerate().rev();
+    assert_eq!(it.clone().collect::<[_; 4]>(), it.collect::<[_; 4]>());
+
+    // A clone of a partially consumed iterator yields the remaining elements
+    let mut it = strings.clone().into_iter_fixed().into_iter();
+    it.next();
+    let rest = it.clone();
+    assert_eq!(rest.len(), 2);
+    assert_eq!(rest.collect::<Vec<_>>(), it.collect::<Vec<_>>());
+}
+
+#[test]
+fn test_random_access() {
+    use std::{panic, rc::Rc};
+
+    let mut a = [1, 2, 3];
+    let b = [10, 20, 30];
+    let sums: [i32; 3] = (&mut a)
+        .into_iter_fixed()
+        .zip(&b)
+        .enumerate()
+        .map(|(i, (a, b))| {
+            *a += b;
+            *a * i as i32
+        })
+        .collect();
+    assert_eq!(a, [11, 22, 33]);
+    assert_eq!(sums, [0, 22, 66]);
+
+    let mut calls = Vec::new();
+    let squares: [usize; 4] = iter_fixed::from_fn(|i| {
+        calls.push(i);
+        i * i
+    })
+    .zip([1, 2, 3, 4])
+    .map(|(a, b)| a + b)
+    .collect();
+    assert_eq!(squares, [1, 3, 7, 13]);
+    assert_eq!(calls, [0, 1, 2, 3]);
+
+    let rotated: [_; 3] = iter_fixed::from_fn(|i| 2 * i)
+        .enumerate()
+        .rotate_left::<1>()
+        .collect();
+    assert_eq!(rotated, [(1, 2), (2, 4), (0, 0)]);
+
+    // Stages without index access are collected in order
+    let mut seen = Vec::new();
+    let c: [_; 3] = [1, 2, 3]
+        .into_iter_fixed()
+        .inspect(|x| seen.push(*x))
+        .zip(&b)
+        .map(|(a, b)| a * b)
+        .collect();
+    assert_eq!(c, [10, 40, 90]);
+    assert_eq!(seen, [1, 2, 3]);
+
+    // The elements produced before a panic are dropped
+    let value = Rc::new(0);
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        let _: [Rc<i32>; 3] = iter_fixed::from_fn(|i| {
+            assert!(i < 2);
+            value.clone()
+        })
+        .collect();
+    }));
+    assert!(result.is_err());
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    #[cfg(feature = "alloc")]
+    {
+        let boxed: Box<[_]> = iter_fixed::from_fn(|i| i as i32 + 1)
+            .zip(&b)
+            .map(|(a, b)| a * b)
+            .collect();
+        assert_eq!(*boxed, [10, 40, 90]);
+    }
+}
+
+#[test]
+fn test_map_in_place_array() {
+    use std::{panic, rc::Rc};
+
+    let res: [Box<u32>; 3] = [1, 2, 3]
+        .map(Rc::new)
+        .into_iter_fixed()
+        .map_in_place(|x| Box::new(*x * 2));
+    assert_eq!(res, [2, 4, 6].map(Box::new));
+
+    // Both the mapped and the remaining elements are dropped if the closure panics
+    let old = Rc::new(0);
+    let new = Rc::new(1);
+    let a = [old.clone(), old.clone(), old.clone(), old.clone()];
+    let res = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        let mut count = 0;
+        a.into_iter_fixed().map_in_place(|x| {
+            count += 1;
+            assert!(count < 3);
+            drop(x);
+            new.clone()
+        })
+    }));
+    assert!(res.is_err());
+    assert_eq!(Rc::strong_count(&old), 1);
+    assert_eq!(Rc::strong_count(&new), 1);
+
+    let res: [u8; 0] = [0i8; 0].into_iter_fixed().map_in_place(|x| x as u8);
+    assert_eq!(res, []);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_map_in_place() {
+    use std::{panic, rc::Rc, thread};
+
+    let a: Box<[Rc<u32>; 3]> = Box::new([1, 2, 3].map(Rc::new));
+    let ptr = &*a as *const [Rc<u32>; 3] as usize;
+    let b: Box<[Box<u32>; 3]> = a.into_iter_fixed().map_in_place(|x| Box::new(*x * 2));
+    assert_eq!(*b, [2, 4, 6].map(Box::new));
+    assert_eq!(&*b as *const [Box<u32>; 3] as usize, ptr);
+
+    // Both the mapped and the remaining elements are dropped if the closure panics
+    let old = Rc::new(0);
+    let new = Rc::new(1);
+    let a = Box::new([old.clone(), old.clone(), old.clone(), old.clone()]);
+    let res = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        let mut count = 0;
+        a.into_iter_fixed().map_in_place(|x| {
+            count += 1;
+            assert!(count < 3);
+            drop(x);
+            new.clone()
+        })
+    }));
+    assert!(res.is_err());
+    assert_eq!(Rc::strong_count(&old), 1);
+    assert_eq!(Rc::strong_count(&new), 1);
+
+    // A 1 MiB buffer is mapped on a thread whose stack could not hold it
+    thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(|| {
+            let samples: Box<[f32; 1 << 18]> = vec![-1.5; 1 << 18].try_into().unwrap();
+            let bits = samples
+                .into_iter_fixed()
+                .map_in_place(|x| x.abs().to_bits());
+            assert!(bits.iter().all(|&b| b == 1.5f32.to_bits()));
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_box_into_iter() {
+    use std::rc::Rc;
+
+    let value = Rc::new(0);
+    let a = Box::new([value.clone(), value.clone(), value.clone()]);
+    let mut it = a.into_iter_fixed().into_iter();
+    assert!(it.next().is_some());
+    let it2 = it.clone();
+    assert_eq!(it2.len(), 2);
+    assert_eq!(Rc::strong_count(&value), 5);
+    drop(it);
+    drop(it2);
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    let a: Vec<i32> = Box::new([1, 2, 3]).into_iter_fixed().rev().collect();
+    assert_eq!(a, [3, 2, 1]);
+}
+
+#[test]
+fn test_into_iter_exact() {
+    fn assert_exact<I: ExactSizeIterator + core::iter::FusedIterator>(_: &I) {}
+
+    // The inner iterator of from_fn does not report an exact size by itself
+    let mut it = iter_fixed::from_fn::<_, _, 4>(|i| i).into_iter();
+    assert_exact(&it);
+    assert_eq!(it.size_hint(), (4, Some(4)));
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.sum::<usize>(), 6);
+
+    let mut it = [1, 2, 3].into_iter_fixed().into_iter();
+    assert_eq!(it.next_back(), Some(3));
+    assert_eq!(it.len(), 2);
+
+    let it = [1, 2, 3]
+        .into_iter_fixed()
+        .zip(core::iter::repeat(0))
+        .into_iter();
+    assert_eq!(it.size_hint(), (3, Some(3)));
+
+    // With TrustedLen collecting allocates exactly N elements
+    #[cfg(feature = "nightly_features")]
+    assert_eq!(it.collect::<Vec<_>>().capacity(), 3);
+}
+
+#[test]
+fn test_fixed_iterator() {
+    use iter_fixed::{FixedIterator, IteratorFixed};
+
+    struct Squares<const N: usize>;
+
+    impl<const N: usize> FixedIterator<N> for Squares<N> {
+        type Item = usize;
+
+        fn into_fixed(self) -> IteratorFixed<impl Iterator<Item = usize>, N> {
+            iter_fixed::from_fn(|i| i * i)
+        }
+    }
+
+    /// Scales every element with a captured factor
+    struct Scaled<const N: usize> {
+        values: [i32; N],
+        factor: i32,
+    }
+
+    impl<const N: usize> FixedIterator<N> for Scaled<N> {
+        type Item = i32;
+
+        fn into_fixed(self) -> IteratorFixed<impl Iterator<Item = i32>, N> {
+            let factor = self.factor;
+            self.values.into_iter_fixed().map(move |x| x * factor)
+        }
+    }
+
+    let a: [_; 4] = Squares.rev().collect();
+    assert_eq!(a, [9, 4, 1, 0]);
+
+    let evens: [bool; 4] = Squares.map(|x| x % 2 == 0).collect();
+    assert_eq!(
+        FixedIterator::count_ones((&evens).into_iter_fixed().copied()),
+        2
+    );
+
+    let scaled = Scaled {
+        values: [1, 2, 3],
+        factor: 10,
+    };
+    let b: [_; 3] = scaled.zip(Squares.into_fixed()).collect();
+    assert_eq!(b, [(10, 0), (20, 1), (30, 4)]);
+
+    #[cfg(feature = "alloc")]
+    {
+        let chain = Squares::<3>.boxed_send();
+        let res = std::thread::spawn(move || chain.collect::<[_; 3]>());
+        assert_eq!(res.join().unwrap(), [0, 1, 4]);
+    }
+
+    #[cfg(feature = "nightly_features")]
+    {
+        let a: [_; 2] = Squares::<4>.skip::<1>().step_by::<2>().collect();
+        assert_eq!(a, [1, 9]);
+
+        let b: [_; 5] = Squares::<2>.chain(Squares::<3>.into_fixed()).collect();
+        assert_eq!(b, [0, 1, 0, 1, 4]);
+    }
+}
+
+#[test]
+fn test_either() {
+    use iter_fixed::Either;
+
+    fn transform(a: [i32; 4], mode: u8) -> [i32; 4] {
+        let it = match mode {
+            0 => a.into_iter_fixed().rev().left_fixed(),
+            1 => a.into_iter_fixed().map(|x| -x).left_fixed().right_fixed(),
+            _ => a
+                .into_iter_fixed()
+                .rotate_left::<1>()
+                .right_fixed()
+                .right_fixed(),
+        };
+        let mut it = it.into_iter();
+        assert_eq!(it.len(), 4);
+        let res = [(); 4].map(|()| it.next().unwrap());
+        assert_eq!(it.next(), None);
+        res
+    }
+
+    let a = [1, 2, 3, 4];
+    assert_eq!(transform(a, 0), [4, 3, 2, 1]);
+    assert_eq!(transform(a, 1), [-1, -2, -3, -4]);
+    assert_eq!(transform(a, 2), [2, 3, 4, 1]);
+
+    let source: Either<[i32; 2], [i32; 2]> = Either::Right([5, 6]);
+    let b: [_; 2] = source.into_iter_fixed().rev().collect();
+    assert_eq!(b, [6, 5]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_boxed() {
+    use iter_fixed::{BoxedFixed, FixedIterator};
+
+    let scale = 3;
+    type Stage = fn(BoxedFixed<'static, i32, 4>) -> BoxedFixed<'static, i32, 4>;
+    let stages: Vec<Stage> = vec![
+        |it| it.rotate_left::<1>().boxed(),
+        |it| it.map(|x| x + 1).boxed(),
+        |it| it.sorted().boxed(),
+    ];
+
+    let mut it = [3, 1, 4, 1].into_iter_fixed().boxed();
+    for stage in stages {
+        it = stage(it);
+    }
+    let res: [_; 4] = it.map(|x| x * scale).collect();
+    assert_eq!(res, [6, 6, 12, 15]);
+
+    // Boxing an already boxed iterator through the trait keeps the length
+    let it = FixedIterator::boxed([1, 2].into_iter_fixed().boxed());
+    assert_eq!(it.into_iter().len(), 2);
+}
@@ -0,0 +1,5 @@
+use iter_fixed::IntoIteratorFixed;
+
+fn main() {
+    let _: [i32; 4] = [1, 2, 3].into_iter_fixed().map(|x| 2 * x).collect();
+}
@@ -0,0 +1,5 @@
+use iter_fixed::IntoIteratorFixed;
+
+fn main() {
+    let _ = [1, 2, 3].into_iter_fixed().zip([4, 5, 6, 7].into_iter_fixed());
+}